@@ -10,7 +10,7 @@ export default async function handler(req: Request) {
   }
 
   try {
-    const { userBadges, jobs, userCompletions } = await req.json();
+    const { userBadges, jobs, userCompletions, userReputation } = await req.json();
 
     // Validate input
     if (!jobs || !Array.isArray(jobs)) {
@@ -39,12 +39,13 @@ export default async function handler(req: Request) {
 **User Profile:**
 - Badges earned: ${JSON.stringify(userBadges || [], null, 2)}
 - Completed contracts: ${userCompletions || 0}
+- Average review score: ${userReputation?.reviewCount ? `${(userReputation.averageScore / 100).toFixed(2)}/5 from ${userReputation.reviewCount} reviews` : 'No reviews yet'}
 
 **Available Jobs:**
 ${JSON.stringify(jobs, null, 2)}
 
 **Task:**
-Analyze the user's skills (from badges) and experience (from completions) against the available jobs.
+Analyze the user's skills (from badges), experience (from completions) and client feedback (from reviews) against the available jobs.
 Rank each job by fit score (0-100) and provide a brief reason why it's a good match.
 
 **Return Format (JSON only, no markdown):**
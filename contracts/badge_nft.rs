@@ -1,11 +1,31 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    hash::hashv,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked, ID as INSTRUCTIONS_SYSVAR_ID},
+};
+use anchor_lang::system_program::{create_account, transfer, CreateAccount, Transfer};
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{create as create_associated_token_account, AssociatedToken, Create as CreateAssociatedToken},
     metadata::{
-        create_metadata_accounts_v3, CreateMetadataAccountsV3, Metadata,
-        mpl_token_metadata::types::DataV2,
+        create_master_edition_v3, create_metadata_accounts_v3, sign_metadata,
+        unverify_sized_collection_item, update_metadata_accounts_v2, verify_sized_collection_item,
+        CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata, MetadataAccount, SignMetadata,
+        UnverifySizedCollectionItem, UpdateMetadataAccountsV2, VerifySizedCollectionItem,
+        mpl_token_metadata::types::{Collection, CollectionDetails, Creator, DataV2},
+    },
+    token_2022::{
+        initialize_mint2, InitializeMint2, Token2022,
+        spl_token_2022::{extension::ExtensionType, state::Mint as Token2022Mint},
     },
-    token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface},
+    token_interface::{
+        burn, mint_to, non_transferable_mint_initialize, permanent_delegate_initialize, Burn, Mint,
+        MintTo, NonTransferableMintInitialize, PermanentDelegateInitialize, TokenAccount,
+        TokenInterface,
+    },
+};
+use credchain::{
+    program::Credchain, Contract as CredchainContract, ContractStatus as CredchainContractStatus,
 };
 
 declare_id!("79s9nmY3ZtsWeKakiBMyagHi6652AGSR413BXRZDZu7Z");
@@ -20,6 +40,8 @@ pub mod badge_nft {
         authority.admin = ctx.accounts.admin.key();
         authority.total_badges_minted = 0;
         authority.total_job_badges_minted = 0;
+        authority.skill_base_uris = Vec::new();
+        authority.job_base_uri = DEFAULT_METADATA_BASE_URI.to_string();
         authority.bump = ctx.bumps.authority;
 
         msg!("Badge NFT program initialized");
@@ -27,28 +49,407 @@ pub mod badge_nft {
     }
 
     
+    pub fn migrate_program_authority(ctx: Context<MigrateProgramAuthority>) -> Result<()> {
+        let authority_info = ctx.accounts.authority.to_account_info();
+        let space = 8 + ProgramAuthority::INIT_SPACE;
+        require!(authority_info.data_len() < space, ErrorCode::AccountAlreadyMigrated);
+
+        let legacy = {
+            let data = authority_info.try_borrow_data()?;
+            require!(data[..8] == ProgramAuthority::DISCRIMINATOR[..], ErrorCode::AccountAlreadyMigrated);
+            LegacyProgramAuthority::deserialize(&mut &data[8..])?
+        };
+        require!(legacy.admin == ctx.accounts.admin.key(), ErrorCode::UnauthorizedAdmin);
+
+        let authority = ProgramAuthority {
+            admin: legacy.admin,
+            total_badges_minted: legacy.total_badges_minted,
+            total_job_badges_minted: legacy.total_job_badges_minted,
+            skill_base_uris: Vec::new(),
+            job_base_uri: DEFAULT_METADATA_BASE_URI.to_string(),
+            bump: legacy.bump,
+        };
+
+        resize_account(
+            &authority_info,
+            space,
+            &ctx.accounts.admin.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        let mut data = authority_info.try_borrow_mut_data()?;
+        authority.try_serialize(&mut &mut data[..])?;
+
+        msg!("Program authority migrated");
+        Ok(())
+    }
+
+    
+    pub fn migrate_test_result(ctx: Context<MigrateTestResult>) -> Result<()> {
+        let test_result_info = ctx.accounts.test_result.to_account_info();
+        let space = 8 + TestResult::INIT_SPACE;
+        require!(test_result_info.data_len() < space, ErrorCode::AccountAlreadyMigrated);
+
+        let legacy = {
+            let data = test_result_info.try_borrow_data()?;
+            require!(data[..8] == TestResult::DISCRIMINATOR[..], ErrorCode::AccountAlreadyMigrated);
+            LegacyTestResult::deserialize(&mut &data[8..])?
+        };
+        let test_result = TestResult::from_legacy(legacy);
+
+        resize_account(
+            &test_result_info,
+            space,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        let mut data = test_result_info.try_borrow_mut_data()?;
+        test_result.try_serialize(&mut &mut data[..])?;
+
+        msg!("Test result migrated for: {}", test_result.candidate);
+        Ok(())
+    }
+
+    
+    pub fn migrate_badge(ctx: Context<MigrateBadge>) -> Result<()> {
+        let badge_info = ctx.accounts.badge.to_account_info();
+        let space = 8 + Badge::INIT_SPACE;
+        require!(badge_info.data_len() < space, ErrorCode::AccountAlreadyMigrated);
+
+        let legacy = {
+            let data = badge_info.try_borrow_data()?;
+            require!(data[..8] == Badge::DISCRIMINATOR[..], ErrorCode::AccountAlreadyMigrated);
+            LegacyBadge::deserialize(&mut &data[8..])?
+        };
+        let tier_config = &ctx.accounts.tier_config;
+        require!(
+            tier_config.skill_category == legacy.skill_category,
+            ErrorCode::TestCategoryMismatch
+        );
+        let tier = tier_config.tier_for(legacy.test_score, false);
+        let badge = Badge::from_legacy(legacy, tier);
+
+        resize_account(
+            &badge_info,
+            space,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        let mut data = badge_info.try_borrow_mut_data()?;
+        badge.try_serialize(&mut &mut data[..])?;
+
+        msg!("Badge migrated: {}", badge.mint);
+        Ok(())
+    }
+
+    
+    pub fn migrate_job_badge(ctx: Context<MigrateJobBadge>) -> Result<()> {
+        let job_badge_info = ctx.accounts.job_badge.to_account_info();
+        let space = 8 + JobCompletionBadge::INIT_SPACE;
+        require!(job_badge_info.data_len() < space, ErrorCode::AccountAlreadyMigrated);
+
+        let legacy = {
+            let data = job_badge_info.try_borrow_data()?;
+            require!(data[..8] == JobCompletionBadge::DISCRIMINATOR[..], ErrorCode::AccountAlreadyMigrated);
+            LegacyJobCompletionBadge::deserialize(&mut &data[8..])?
+        };
+        let job_badge = JobCompletionBadge::from_legacy(legacy);
+
+        resize_account(
+            &job_badge_info,
+            space,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        let mut data = job_badge_info.try_borrow_mut_data()?;
+        job_badge.try_serialize(&mut &mut data[..])?;
+
+        msg!("Job badge migrated: {}", job_badge.mint);
+        Ok(())
+    }
+
+    
+    pub fn set_metadata_base_uri(
+        ctx: Context<SetMetadataBaseUri>,
+        collection: BadgeCollection,
+        base_uri: String,
+    ) -> Result<()> {
+        let base_uri = base_uri.trim_end_matches('/').to_string();
+        require!(
+            !base_uri.is_empty() && base_uri.len() <= MAX_BASE_URI_LENGTH,
+            ErrorCode::InvalidBaseUri
+        );
+
+        let authority = &mut ctx.accounts.authority;
+        match &collection {
+            BadgeCollection::Skill(skill_category) => {
+                if let Some(entry) = authority.skill_base_uris.iter_mut().find(|e| &e.skill_category == skill_category) {
+                    entry.base_uri = base_uri.clone();
+                } else {
+                    authority.skill_base_uris.push(CategoryBaseUri {
+                        skill_category: skill_category.clone(),
+                        base_uri: base_uri.clone(),
+                    });
+                }
+            }
+            BadgeCollection::JobCompletion => {
+                authority.job_base_uri = base_uri.clone();
+            }
+        }
+
+        msg!("Metadata base URI updated for {}: {}", collection.name(), base_uri);
+        Ok(())
+    }
+
+    
+    pub fn create_badge_collection(
+        ctx: Context<CreateBadgeCollection>,
+        collection: BadgeCollection,
+        uri: String,
+    ) -> Result<()> {
+        require!(uri.len() <= MAX_METADATA_URI_LENGTH, ErrorCode::MetadataUriTooLong);
+
+        let authority_seeds = &[b"authority" as &[u8], &[ctx.accounts.authority.bump]];
+        let signer = &[&authority_seeds[..]];
+
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.collection_mint.to_account_info(),
+                    to: ctx.accounts.collection_token_account.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+                signer,
+            ),
+            1,
+        )?;
+
+        let data_v2 = DataV2 {
+            name: collection.name(),
+            symbol: collection.symbol(),
+            uri,
+            seller_fee_basis_points: 0,
+            creators: Some(vec![Creator {
+                address: ctx.accounts.authority.key(),
+                verified: true,
+                share: 100,
+            }]),
+            collection: None,
+            uses: None,
+        };
+
+        create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                ctx.accounts.metadata_program.to_account_info(),
+                CreateMetadataAccountsV3 {
+                    metadata: ctx.accounts.collection_metadata.to_account_info(),
+                    mint: ctx.accounts.collection_mint.to_account_info(),
+                    mint_authority: ctx.accounts.authority.to_account_info(),
+                    update_authority: ctx.accounts.authority.to_account_info(),
+                    payer: ctx.accounts.admin.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                signer,
+            ),
+            data_v2,
+            true,
+            true,
+            Some(CollectionDetails::V1 { size: 0 }),
+        )?;
+
+        create_master_edition_v3(
+            CpiContext::new_with_signer(
+                ctx.accounts.metadata_program.to_account_info(),
+                CreateMasterEditionV3 {
+                    edition: ctx.accounts.collection_master_edition.to_account_info(),
+                    mint: ctx.accounts.collection_mint.to_account_info(),
+                    update_authority: ctx.accounts.authority.to_account_info(),
+                    mint_authority: ctx.accounts.authority.to_account_info(),
+                    payer: ctx.accounts.admin.to_account_info(),
+                    metadata: ctx.accounts.collection_metadata.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                signer,
+            ),
+            Some(0),
+        )?;
+
+        emit!(BadgeCollectionCreatedEvent {
+            collection: collection.clone(),
+            mint: ctx.accounts.collection_mint.key(),
+        });
+
+        msg!("Badge collection created: {}", collection.name());
+        Ok(())
+    }
+
+    
+    pub fn register_issuer(
+        ctx: Context<RegisterIssuer>,
+        issuer_id: String,
+        signing_key: Pubkey,
+        name: String,
+        categories: Vec<SkillCategory>,
+        quota: u32,
+    ) -> Result<()> {
+        require!(issuer_id.len() <= 32, ErrorCode::IssuerIdTooLong);
+        require!(name.len() <= 64, ErrorCode::IssuerNameTooLong);
+        require!(categories.len() > 0 && categories.len() <= 6, ErrorCode::InvalidIssuerCategories);
+
+        let issuer = &mut ctx.accounts.issuer;
+        issuer.issuer_id = issuer_id;
+        issuer.signing_key = signing_key;
+        issuer.name = name;
+        issuer.categories = categories;
+        issuer.quota = quota;
+        issuer.issued_count = 0;
+        issuer.status = IssuerStatus::Active;
+        issuer.registered_at = Clock::get()?.unix_timestamp;
+        issuer.bump = ctx.bumps.issuer;
+
+        emit!(IssuerUpdatedEvent {
+            issuer: issuer.key(),
+            signing_key,
+            status: IssuerStatus::Active,
+        });
+
+        msg!("Issuer registered: {}", issuer.name);
+        Ok(())
+    }
+
+    
+    pub fn update_issuer(
+        ctx: Context<UpdateIssuer>,
+        signing_key: Pubkey,
+        categories: Vec<SkillCategory>,
+        quota: u32,
+        status: IssuerStatus,
+    ) -> Result<()> {
+        require!(categories.len() > 0 && categories.len() <= 6, ErrorCode::InvalidIssuerCategories);
+
+        let issuer = &mut ctx.accounts.issuer;
+        issuer.signing_key = signing_key;
+        issuer.categories = categories;
+        issuer.quota = quota;
+        issuer.status = status.clone();
+
+        emit!(IssuerUpdatedEvent {
+            issuer: issuer.key(),
+            signing_key: issuer.signing_key,
+            status,
+        });
+
+        msg!("Issuer updated: {}", issuer.name);
+        Ok(())
+    }
+
+    
+    pub fn initialize_tier_config(
+        ctx: Context<InitializeTierConfig>,
+        skill_category: SkillCategory,
+        silver_min_score: u8,
+        gold_min_score: u8,
+    ) -> Result<()> {
+        validate_tier_bands(silver_min_score, gold_min_score)?;
+
+        let tier_config = &mut ctx.accounts.tier_config;
+        tier_config.skill_category = skill_category.clone();
+        tier_config.silver_min_score = silver_min_score;
+        tier_config.gold_min_score = gold_min_score;
+        tier_config.bump = ctx.bumps.tier_config;
+
+        msg!("Tier config initialized: {}", skill_category.to_string());
+        Ok(())
+    }
+
+    
+    pub fn update_tier_config(
+        ctx: Context<UpdateTierConfig>,
+        silver_min_score: u8,
+        gold_min_score: u8,
+    ) -> Result<()> {
+        validate_tier_bands(silver_min_score, gold_min_score)?;
+
+        let tier_config = &mut ctx.accounts.tier_config;
+        tier_config.silver_min_score = silver_min_score;
+        tier_config.gold_min_score = gold_min_score;
+
+        msg!("Tier config updated: {}", tier_config.skill_category.to_string());
+        Ok(())
+    }
+
+    
+    pub fn initialize_attempt_tracker(
+        ctx: Context<InitializeAttemptTracker>,
+        skill_category: SkillCategory,
+    ) -> Result<()> {
+        let tracker = &mut ctx.accounts.attempt_tracker;
+        tracker.candidate = ctx.accounts.candidate.key();
+        tracker.skill_category = skill_category.clone();
+        tracker.total_attempts = 0;
+        tracker.failed_attempts = 0;
+        tracker.last_attempt_at = 0;
+        tracker.recent_attempts = Vec::new();
+        tracker.bump = ctx.bumps.attempt_tracker;
+
+        msg!("Attempt tracker initialized: {}", skill_category.to_string());
+        Ok(())
+    }
+
+    
     pub fn record_test_completion(
         ctx: Context<RecordTestCompletion>,
         skill_category: SkillCategory,
         score: u8,
         duration: i64,
         proctored: bool,
-        _test_nonce: u64,
+        test_nonce: u64,
+        expiry: i64,
     ) -> Result<()> {
         require!(score <= 100, ErrorCode::InvalidScore);
-        require!(score >= 70, ErrorCode::ScoreTooLow); 
+        require!(Clock::get()?.unix_timestamp <= expiry, ErrorCode::AttestationExpired);
+
+        ctx.accounts.issuer.record_issuance(&skill_category)?;
+
+        let attestation = TestAttestation {
+            candidate: ctx.accounts.candidate.key(),
+            skill_category: skill_category.clone(),
+            score,
+            duration,
+            proctored,
+            nonce: test_nonce,
+            expiry,
+        };
+        verify_ed25519_signature(
+            &ctx.accounts.instructions,
+            &ctx.accounts.issuer.signing_key,
+            &attestation.try_to_vec()?,
+        )?;
 
         let test_result = &mut ctx.accounts.test_result;
         test_result.candidate = ctx.accounts.candidate.key();
         test_result.skill_category = skill_category.clone();
         test_result.score = score;
-        test_result.passed = score >= 70;
+        test_result.passed = score >= PASSING_SCORE;
         test_result.test_date = Clock::get()?.unix_timestamp;
         test_result.duration = duration;
         test_result.proctored = proctored;
         test_result.badge_minted = false;
+        test_result.issuer = ctx.accounts.issuer.key();
         test_result.bump = ctx.bumps.test_result;
 
+        let tracker = &mut ctx.accounts.attempt_tracker;
+        require!(test_nonce == tracker.total_attempts as u64, ErrorCode::InvalidAttemptNonce);
+        tracker.register_attempt(test_result.test_date)?;
+        tracker.record_outcome(test_result.passed);
+
         emit!(TestCompletedEvent {
             candidate: ctx.accounts.candidate.key(),
             skill_category: skill_category.clone(),
@@ -61,6 +462,161 @@ pub mod badge_nft {
     }
 
     
+    pub fn create_test_session(
+        ctx: Context<CreateTestSession>,
+        skill_category: SkillCategory,
+        session_nonce: u64,
+        answer_key_root: [u8; 32],
+        question_count: u8,
+        time_limit: i64,
+        proctored: bool,
+    ) -> Result<()> {
+        require!(
+            question_count > 0 && question_count as usize <= MAX_TEST_QUESTIONS,
+            ErrorCode::InvalidQuestionCount
+        );
+        require!(time_limit > 0, ErrorCode::InvalidTimeLimit);
+
+        let issuer = &ctx.accounts.issuer;
+        require!(issuer.status == IssuerStatus::Active, ErrorCode::IssuerNotActive);
+        require!(issuer.categories.contains(&skill_category), ErrorCode::IssuerCategoryNotAllowed);
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        let tracker = &mut ctx.accounts.attempt_tracker;
+        require!(session_nonce == tracker.total_attempts as u64, ErrorCode::InvalidAttemptNonce);
+        tracker.register_attempt(current_time)?;
+
+        let session = &mut ctx.accounts.test_session;
+        session.issuer = issuer.key();
+        session.candidate = ctx.accounts.candidate.key();
+        session.skill_category = skill_category.clone();
+        session.nonce = session_nonce;
+        session.answer_key_root = answer_key_root;
+        session.question_count = question_count;
+        session.proctored = proctored;
+        session.start_time = current_time;
+        session.deadline = current_time + time_limit;
+        session.reveal_deadline = session.deadline + ANSWER_KEY_REVEAL_WINDOW;
+        session.answers = Vec::new();
+        session.submitted = false;
+        session.submitted_at = 0;
+        session.graded = false;
+        session.bump = ctx.bumps.test_session;
+
+        emit!(TestSessionCreatedEvent {
+            session: session.key(),
+            candidate: session.candidate,
+            skill_category: skill_category.clone(),
+            deadline: session.deadline,
+        });
+
+        msg!("Test session started: {}", skill_category.to_string());
+        Ok(())
+    }
+
+    
+    pub fn submit_test_answers(
+        ctx: Context<SubmitTestAnswers>,
+        answers: Vec<u8>,
+    ) -> Result<()> {
+        let session = &mut ctx.accounts.test_session;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(!session.submitted, ErrorCode::TestSessionClosed);
+        require!(current_time <= session.deadline, ErrorCode::TestSessionClosed);
+        require!(answers.len() == session.question_count as usize, ErrorCode::AnswerCountMismatch);
+
+        session.answers = answers;
+        session.submitted = true;
+        session.submitted_at = current_time;
+
+        msg!("Test answers submitted");
+        Ok(())
+    }
+
+    
+    pub fn reveal_answer_key(
+        ctx: Context<RevealAnswerKey>,
+        answer_key: Vec<u8>,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        let session = &mut ctx.accounts.test_session;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(!session.graded, ErrorCode::SessionAlreadyGraded);
+        require!(
+            session.submitted || current_time > session.deadline,
+            ErrorCode::TestSessionStillOpen
+        );
+        require!(current_time <= session.reveal_deadline, ErrorCode::RevealDeadlinePassed);
+        require!(answer_key.len() == session.question_count as usize, ErrorCode::AnswerCountMismatch);
+        require!(
+            answer_key_merkle_root(&answer_key, &salt) == session.answer_key_root,
+            ErrorCode::AnswerKeyMismatch
+        );
+
+        ctx.accounts.issuer.record_issuance(&session.skill_category)?;
+
+        let correct = session.answers.iter()
+            .zip(answer_key.iter())
+            .filter(|(given, expected)| given == expected)
+            .count();
+        let score = (correct * 100 / answer_key.len()) as u8;
+
+        session.graded = true;
+
+        let test_result = &mut ctx.accounts.test_result;
+        test_result.candidate = session.candidate;
+        test_result.skill_category = session.skill_category.clone();
+        test_result.score = score;
+        test_result.passed = score >= PASSING_SCORE;
+        test_result.test_date = current_time;
+        test_result.duration = if session.submitted {
+            session.submitted_at - session.start_time
+        } else {
+            session.deadline - session.start_time
+        };
+        test_result.proctored = session.proctored;
+        test_result.badge_minted = false;
+        test_result.issuer = ctx.accounts.issuer.key();
+        test_result.bump = ctx.bumps.test_result;
+
+        ctx.accounts.attempt_tracker.record_outcome(test_result.passed);
+
+        emit!(TestCompletedEvent {
+            candidate: session.candidate,
+            skill_category: session.skill_category.clone(),
+            score,
+            passed: test_result.passed,
+        });
+
+        msg!("Test session graded: {} - Score: {}", session.skill_category.to_string(), score);
+        Ok(())
+    }
+
+    
+    pub fn void_test_session(ctx: Context<VoidTestSession>) -> Result<()> {
+        let session = &ctx.accounts.test_session;
+        require!(!session.graded, ErrorCode::SessionAlreadyGraded);
+        require!(
+            Clock::get()?.unix_timestamp > session.reveal_deadline,
+            ErrorCode::RevealDeadlineNotPassed
+        );
+
+        ctx.accounts.attempt_tracker.refund_attempt(session.start_time);
+
+        emit!(TestSessionVoidedEvent {
+            session: session.key(),
+            candidate: session.candidate,
+            skill_category: session.skill_category.clone(),
+        });
+
+        msg!("Test session voided: {}", session.skill_category.to_string());
+        Ok(())
+    }
+
+    
     pub fn mint_badge(
         ctx: Context<MintBadge>,
         skill_category: SkillCategory,
@@ -72,7 +628,7 @@ pub mod badge_nft {
         let current_time = Clock::get()?.unix_timestamp;
 
         
-        let expiry_date = current_time + (365 * 24 * 60 * 60);
+        let expiry_date = current_time + BADGE_VALIDITY_PERIOD;
 
         
         let candidate_key = ctx.accounts.candidate.key();
@@ -87,6 +643,27 @@ pub mod badge_nft {
         ];
         let signer = &[&seeds[..]];
 
+        create_soulbound_mint(
+            &ctx.accounts.candidate.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            signer,
+            &ctx.accounts.authority.key(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        create_associated_token_account(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            CreateAssociatedToken {
+                payer: ctx.accounts.candidate.to_account_info(),
+                associated_token: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.candidate.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ))?;
+
         mint_to(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
@@ -101,20 +678,28 @@ pub mod badge_nft {
         )?;
 
         
-        let metadata_uri = format!(
-            "https://gateway.pinata.cloud/ipfs/bafybeiaxverp4nugxlsfj6a2waw5psojz763n7il5xx3apx57lfb6xuyqq/{}.json",
-            skill_category_str.to_lowercase().replace(" ", "-")
-        );
+        let tier = ctx.accounts.tier_config.tier_for(test_result.score, test_result.proctored);
 
-        let data_v2 = DataV2 {
-            name: format!("CredChain {} Badge", skill_category_str),
-            symbol: "CRED".to_string(),
-            uri: metadata_uri,
-            seller_fee_basis_points: 0,
-            creators: None,
-            collection: None,
-            uses: None,
-        };
+        
+        let badge = &mut ctx.accounts.badge;
+        badge.mint = ctx.accounts.mint.key();
+        badge.owner = candidate_key;
+        badge.skill_category = skill_category.clone();
+        badge.issue_date = current_time;
+        badge.expiry_date = expiry_date;
+        badge.test_score = test_result.score;
+        badge.is_valid = true;
+        badge.revoked = false;
+        badge.issuer = test_result.issuer;
+        badge.tier = tier.clone();
+        badge.renewal_count = 0;
+        badge.renewal_history = Vec::new();
+        badge.bump = ctx.bumps.badge;
+
+        let base_uri = ctx.accounts.authority.base_uri(&BadgeCollection::Skill(skill_category.clone()));
+        let provenance = badge_provenance(ctx.accounts.authority.key(), ctx.accounts.collection_mint.key());
+        let data_v2 = badge_metadata(&base_uri, &ctx.accounts.badge, provenance);
+        require!(data_v2.uri.len() <= MAX_METADATA_URI_LENGTH, ErrorCode::MetadataUriTooLong);
 
         
         
@@ -140,17 +725,33 @@ pub mod badge_nft {
             None,  
         )?;
 
-        
-        let badge = &mut ctx.accounts.badge;
-        badge.mint = ctx.accounts.mint.key();
-        badge.owner = candidate_key;
-        badge.skill_category = skill_category.clone();
-        badge.issue_date = current_time;
-        badge.expiry_date = expiry_date;
-        badge.test_score = test_result.score;
-        badge.is_valid = true;
-        badge.revoked = false;
-        badge.bump = ctx.bumps.badge;
+        let authority_seeds = &[b"authority" as &[u8], &[ctx.accounts.authority.bump]];
+        let authority_signer = &[&authority_seeds[..]];
+
+        sign_metadata(CpiContext::new_with_signer(
+            ctx.accounts.metadata_program.to_account_info(),
+            SignMetadata {
+                creator: ctx.accounts.authority.to_account_info(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+            },
+            authority_signer,
+        ))?;
+
+        verify_sized_collection_item(
+            CpiContext::new_with_signer(
+                ctx.accounts.metadata_program.to_account_info(),
+                VerifySizedCollectionItem {
+                    payer: ctx.accounts.candidate.to_account_info(),
+                    metadata: ctx.accounts.metadata.to_account_info(),
+                    collection_authority: ctx.accounts.authority.to_account_info(),
+                    collection_mint: ctx.accounts.collection_mint.to_account_info(),
+                    collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+                    collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+                },
+                authority_signer,
+            ),
+            None,
+        )?;
 
         
         test_result.badge_minted = true;
@@ -164,6 +765,7 @@ pub mod badge_nft {
             skill_category: skill_category.clone(),
             mint: ctx.accounts.mint.key(),
             score: test_result.score,
+            tier,
             expiry_date,
         });
 
@@ -172,12 +774,138 @@ pub mod badge_nft {
     }
 
     
-    pub fn verify_badge(ctx: Context<VerifyBadge>) -> Result<VerificationResult> {
-        let badge = &ctx.accounts.badge;
+    pub fn renew_badge(ctx: Context<RenewBadge>) -> Result<()> {
+        let test_result = &mut ctx.accounts.test_result;
+        let badge = &mut ctx.accounts.badge;
+        require!(!badge.revoked, ErrorCode::BadgeAlreadyRevoked);
+        require!(test_result.passed, ErrorCode::TestNotPassed);
+        require!(!test_result.badge_minted, ErrorCode::BadgeAlreadyMinted);
+        require!(
+            test_result.skill_category == badge.skill_category,
+            ErrorCode::TestResultMismatch
+        );
+
+        let last_certified = badge.renewal_history.last()
+            .map(|r| r.renewed_at)
+            .unwrap_or(badge.issue_date);
+        require!(test_result.test_date > last_certified, ErrorCode::StaleTestResult);
+
         let current_time = Clock::get()?.unix_timestamp;
+        let previous_expiry = badge.expiry_date;
 
-        let is_expired = current_time > badge.expiry_date;
-        let is_valid = badge.is_valid && !badge.revoked && !is_expired;
+        if badge.renewal_history.len() >= MAX_RENEWAL_HISTORY {
+            badge.renewal_history.remove(0);
+        }
+        badge.renewal_history.push(BadgeRenewal {
+            renewed_at: current_time,
+            previous_expiry,
+            previous_score: badge.test_score,
+            test_result: test_result.key(),
+        });
+
+        badge.expiry_date = current_time + BADGE_VALIDITY_PERIOD;
+        badge.test_score = test_result.score;
+        badge.tier = ctx.accounts.tier_config.tier_for(test_result.score, test_result.proctored);
+        badge.issuer = test_result.issuer;
+        badge.is_valid = true;
+        badge.renewal_count += 1;
+
+        test_result.badge_minted = true;
+
+        let skill_category_str = badge.skill_category.to_string();
+        let seeds = &[
+            b"badge-mint" as &[u8],
+            badge.owner.as_ref(),
+            skill_category_str.as_bytes(),
+            &[ctx.bumps.mint],
+        ];
+
+        let base_uri = ctx.accounts.authority.base_uri(&BadgeCollection::Skill(badge.skill_category.clone()));
+        update_badge_metadata(
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &[&seeds[..]],
+            &ctx.accounts.metadata_program.to_account_info(),
+            badge_metadata(&base_uri, badge, existing_provenance(&ctx.accounts.metadata)?),
+        )?;
+
+        emit!(BadgeRenewedEvent {
+            badge: badge.key(),
+            owner: badge.owner,
+            skill_category: badge.skill_category.clone(),
+            score: badge.test_score,
+            expiry_date: badge.expiry_date,
+            renewal_count: badge.renewal_count,
+        });
+
+        msg!("Badge renewed: {} (renewal #{})", badge.skill_category.to_string(), badge.renewal_count);
+        Ok(())
+    }
+
+    
+    pub fn upgrade_badge_tier(ctx: Context<UpgradeBadgeTier>) -> Result<()> {
+        let test_result = &mut ctx.accounts.test_result;
+        let badge = &mut ctx.accounts.badge;
+        require!(!badge.revoked, ErrorCode::BadgeAlreadyRevoked);
+        require!(
+            badge.is_valid && Clock::get()?.unix_timestamp <= badge.expiry_date,
+            ErrorCode::BadgeExpired
+        );
+        require!(test_result.passed, ErrorCode::TestNotPassed);
+        require!(!test_result.badge_minted, ErrorCode::BadgeAlreadyMinted);
+        require!(
+            test_result.skill_category == badge.skill_category,
+            ErrorCode::TestResultMismatch
+        );
+
+        let tier = ctx.accounts.tier_config.tier_for(test_result.score, test_result.proctored);
+        require!(tier > badge.tier, ErrorCode::TierNotHigher);
+
+        let candidate_key = ctx.accounts.candidate.key();
+        let skill_category_str = badge.skill_category.to_string();
+        let seeds = &[
+            b"badge-mint" as &[u8],
+            candidate_key.as_ref(),
+            skill_category_str.as_bytes(),
+            &[ctx.bumps.mint],
+        ];
+        let signer = &[&seeds[..]];
+
+        let previous_tier = badge.tier.clone();
+        badge.tier = tier.clone();
+        badge.test_score = test_result.score;
+        test_result.badge_minted = true;
+
+        let base_uri = ctx.accounts.authority.base_uri(&BadgeCollection::Skill(badge.skill_category.clone()));
+        update_badge_metadata(
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            signer,
+            &ctx.accounts.metadata_program.to_account_info(),
+            badge_metadata(&base_uri, badge, existing_provenance(&ctx.accounts.metadata)?),
+        )?;
+
+        emit!(BadgeTierUpgradedEvent {
+            badge: badge.key(),
+            owner: badge.owner,
+            skill_category: badge.skill_category.clone(),
+            previous_tier,
+            tier,
+        });
+
+        msg!("Badge tier upgraded: {}", skill_category_str);
+        Ok(())
+    }
+
+    
+    pub fn verify_badge(ctx: Context<VerifyBadge>) -> Result<VerificationResult> {
+        let badge = &ctx.accounts.badge;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        let is_expired = current_time > badge.expiry_date;
+        let token_account = &ctx.accounts.token_account;
+        let is_held_by_owner = token_account.owner == badge.owner && token_account.amount == 1;
+        let is_valid = badge.is_valid && !badge.revoked && !is_expired && is_held_by_owner;
 
         let result = VerificationResult {
             owner: badge.owner,
@@ -188,6 +916,9 @@ pub mod badge_nft {
             is_valid,
             is_expired,
             is_revoked: badge.revoked,
+            is_held_by_owner,
+            issuer: badge.issuer,
+            tier: badge.tier.clone(),
         };
 
         emit!(BadgeVerifiedEvent {
@@ -213,10 +944,47 @@ pub mod badge_nft {
 
         let owner = badge.owner;
         let skill_category = badge.skill_category.clone();
+        let skill_category_str = skill_category.to_string();
+
+        let authority_seeds = &[b"authority" as &[u8], &[ctx.accounts.authority.bump]];
+        let mint_seeds = &[
+            b"badge-mint" as &[u8],
+            owner.as_ref(),
+            skill_category_str.as_bytes(),
+            &[ctx.bumps.mint],
+        ];
 
         badge.revoked = true;
         badge.is_valid = false;
 
+        burn_badge_token(
+            &ctx.accounts.authority.to_account_info(),
+            &[&authority_seeds[..]],
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.token_account.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+        )?;
+
+        let provenance = unverify_collection_item(
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &[&authority_seeds[..]],
+            &ctx.accounts.admin.to_account_info(),
+            &ctx.accounts.collection_mint.to_account_info(),
+            &ctx.accounts.collection_metadata.to_account_info(),
+            &ctx.accounts.collection_master_edition.to_account_info(),
+            &ctx.accounts.metadata_program.to_account_info(),
+        )?;
+
+        let base_uri = ctx.accounts.authority.base_uri(&BadgeCollection::Skill(skill_category.clone()));
+        update_badge_metadata(
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &[&mint_seeds[..]],
+            &ctx.accounts.metadata_program.to_account_info(),
+            badge_metadata(&base_uri, badge, provenance),
+        )?;
+
         emit!(BadgeRevokedEvent {
             badge: badge_key,
             owner,
@@ -229,6 +997,166 @@ pub mod badge_nft {
     }
 
     
+    pub fn revoke_job_badge(
+        ctx: Context<RevokeJobBadge>,
+        reason: String,
+    ) -> Result<()> {
+        let job_badge_key = ctx.accounts.job_badge.key();
+
+        let job_badge = &mut ctx.accounts.job_badge;
+        require!(!job_badge.revoked, ErrorCode::BadgeAlreadyRevoked);
+
+        let freelancer = job_badge.freelancer;
+        let contract_id = job_badge.contract_id.clone();
+
+        let authority_seeds = &[b"authority" as &[u8], &[ctx.accounts.authority.bump]];
+        let mint_seeds = &[
+            b"job-badge-mint" as &[u8],
+            freelancer.as_ref(),
+            contract_id.as_bytes(),
+            &[ctx.bumps.mint],
+        ];
+
+        job_badge.revoked = true;
+        job_badge.is_valid = false;
+
+        burn_badge_token(
+            &ctx.accounts.authority.to_account_info(),
+            &[&authority_seeds[..]],
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.token_account.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+        )?;
+
+        let provenance = unverify_collection_item(
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &[&authority_seeds[..]],
+            &ctx.accounts.admin.to_account_info(),
+            &ctx.accounts.collection_mint.to_account_info(),
+            &ctx.accounts.collection_metadata.to_account_info(),
+            &ctx.accounts.collection_master_edition.to_account_info(),
+            &ctx.accounts.metadata_program.to_account_info(),
+        )?;
+
+        let base_uri = ctx.accounts.authority.base_uri(&BadgeCollection::JobCompletion);
+        update_badge_metadata(
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &[&mint_seeds[..]],
+            &ctx.accounts.metadata_program.to_account_info(),
+            job_badge_metadata(&base_uri, job_badge, provenance),
+        )?;
+
+        emit!(JobBadgeRevokedEvent {
+            job_badge: job_badge_key,
+            freelancer,
+            contract_id,
+            reason,
+        });
+
+        msg!("Job badge revoked");
+        Ok(())
+    }
+
+    
+    pub fn revoke_legacy_badge(
+        ctx: Context<RevokeLegacyBadge>,
+        reason: String,
+    ) -> Result<()> {
+        require!(!is_soulbound_mint(&ctx.accounts.mint)?, ErrorCode::NotLegacyBadge);
+
+        let badge_key = ctx.accounts.badge.key();
+        let badge = &mut ctx.accounts.badge;
+        require!(!badge.revoked, ErrorCode::BadgeAlreadyRevoked);
+
+        badge.revoked = true;
+        badge.is_valid = false;
+
+        emit!(BadgeRevokedEvent {
+            badge: badge_key,
+            owner: badge.owner,
+            skill_category: badge.skill_category.clone(),
+            reason,
+        });
+
+        msg!("Legacy badge revoked");
+        Ok(())
+    }
+
+    
+    pub fn revoke_legacy_job_badge(
+        ctx: Context<RevokeLegacyJobBadge>,
+        reason: String,
+    ) -> Result<()> {
+        require!(!is_soulbound_mint(&ctx.accounts.mint)?, ErrorCode::NotLegacyBadge);
+
+        let job_badge_key = ctx.accounts.job_badge.key();
+        let job_badge = &mut ctx.accounts.job_badge;
+        require!(!job_badge.revoked, ErrorCode::BadgeAlreadyRevoked);
+
+        job_badge.revoked = true;
+        job_badge.is_valid = false;
+
+        emit!(JobBadgeRevokedEvent {
+            job_badge: job_badge_key,
+            freelancer: job_badge.freelancer,
+            contract_id: job_badge.contract_id.clone(),
+            reason,
+        });
+
+        msg!("Legacy job badge revoked");
+        Ok(())
+    }
+
+    
+    pub fn refresh_badge_metadata(ctx: Context<RefreshBadgeMetadata>) -> Result<()> {
+        let badge = &ctx.accounts.badge;
+        let skill_category_str = badge.skill_category.to_string();
+        let seeds = &[
+            b"badge-mint" as &[u8],
+            badge.owner.as_ref(),
+            skill_category_str.as_bytes(),
+            &[ctx.bumps.mint],
+        ];
+
+        let base_uri = ctx.accounts.authority.base_uri(&BadgeCollection::Skill(badge.skill_category.clone()));
+        update_badge_metadata(
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &[&seeds[..]],
+            &ctx.accounts.metadata_program.to_account_info(),
+            badge_metadata(&base_uri, badge, existing_provenance(&ctx.accounts.metadata)?),
+        )?;
+
+        msg!("Badge metadata refreshed: {}", skill_category_str);
+        Ok(())
+    }
+
+    
+    pub fn refresh_job_badge_metadata(ctx: Context<RefreshJobBadgeMetadata>) -> Result<()> {
+        let job_badge = &ctx.accounts.job_badge;
+        let seeds = &[
+            b"job-badge-mint" as &[u8],
+            job_badge.freelancer.as_ref(),
+            job_badge.contract_id.as_bytes(),
+            &[ctx.bumps.mint],
+        ];
+
+        let base_uri = ctx.accounts.authority.base_uri(&BadgeCollection::JobCompletion);
+        update_badge_metadata(
+            &ctx.accounts.metadata.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &[&seeds[..]],
+            &ctx.accounts.metadata_program.to_account_info(),
+            job_badge_metadata(&base_uri, job_badge, existing_provenance(&ctx.accounts.metadata)?),
+        )?;
+
+        msg!("Job badge metadata refreshed: {}", job_badge.contract_id);
+        Ok(())
+    }
+
+    
     pub fn update_leaderboard(
         ctx: Context<UpdateLeaderboard>,
         skill_category: SkillCategory,
@@ -269,12 +1197,25 @@ pub mod badge_nft {
     pub fn mint_job_completion_badge(
         ctx: Context<MintJobCompletionBadge>,
         contract_id: String,
-        job_title: String,
-        contract_amount: u64,
     ) -> Result<()> {
         let current_time = Clock::get()?.unix_timestamp;
         let freelancer_key = ctx.accounts.freelancer.key();
 
+        let contract = &ctx.accounts.contract;
+        require!(
+            contract.status == CredchainContractStatus::Completed,
+            ErrorCode::ContractNotCompleted
+        );
+        require!(
+            contract.freelancer == freelancer_key ||
+            contract.contributors.iter().any(|c| c.wallet == freelancer_key),
+            ErrorCode::NotContractFreelancer
+        );
+        require!(contract.client == ctx.accounts.client.key(), ErrorCode::ClientMismatch);
+
+        let job_title = contract.title.clone();
+        let contract_amount = contract.paid_amount;
+
         
         let job_badge = &mut ctx.accounts.job_badge;
         job_badge.mint = ctx.accounts.mint.key();
@@ -285,6 +1226,7 @@ pub mod badge_nft {
         job_badge.completion_date = current_time;
         job_badge.contract_amount = contract_amount;
         job_badge.is_valid = true;
+        job_badge.revoked = false;
         job_badge.bump = ctx.bumps.job_badge;
 
         
@@ -296,6 +1238,27 @@ pub mod badge_nft {
         ];
         let signer = &[&seeds[..]];
 
+        create_soulbound_mint(
+            &ctx.accounts.freelancer.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            signer,
+            &ctx.accounts.authority.key(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        create_associated_token_account(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            CreateAssociatedToken {
+                payer: ctx.accounts.freelancer.to_account_info(),
+                associated_token: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.freelancer.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ))?;
+
         mint_to(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
@@ -310,17 +1273,10 @@ pub mod badge_nft {
         )?;
 
         
-        let metadata_uri = "https://gateway.pinata.cloud/ipfs/bafybeiaxverp4nugxlsfj6a2waw5psojz763n7il5xx3apx57lfb6xuyqq/job-completion-template.json".to_string();
-
-        let data_v2 = DataV2 {
-            name: format!("CredChain Job: {}", job_title),
-            symbol: "CRED-JOB".to_string(),
-            uri: metadata_uri,
-            seller_fee_basis_points: 0,
-            creators: None,
-            collection: None,
-            uses: None,
-        };
+        let base_uri = ctx.accounts.authority.base_uri(&BadgeCollection::JobCompletion);
+        let provenance = badge_provenance(ctx.accounts.authority.key(), ctx.accounts.collection_mint.key());
+        let data_v2 = job_badge_metadata(&base_uri, &ctx.accounts.job_badge, provenance);
+        require!(data_v2.uri.len() <= MAX_METADATA_URI_LENGTH, ErrorCode::MetadataUriTooLong);
 
         let mint_info = ctx.accounts.mint.to_account_info();
 
@@ -344,6 +1300,34 @@ pub mod badge_nft {
             None,
         )?;
 
+        let authority_seeds = &[b"authority" as &[u8], &[ctx.accounts.authority.bump]];
+        let authority_signer = &[&authority_seeds[..]];
+
+        sign_metadata(CpiContext::new_with_signer(
+            ctx.accounts.metadata_program.to_account_info(),
+            SignMetadata {
+                creator: ctx.accounts.authority.to_account_info(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+            },
+            authority_signer,
+        ))?;
+
+        verify_sized_collection_item(
+            CpiContext::new_with_signer(
+                ctx.accounts.metadata_program.to_account_info(),
+                VerifySizedCollectionItem {
+                    payer: ctx.accounts.freelancer.to_account_info(),
+                    metadata: ctx.accounts.metadata.to_account_info(),
+                    collection_authority: ctx.accounts.authority.to_account_info(),
+                    collection_mint: ctx.accounts.collection_mint.to_account_info(),
+                    collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+                    collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+                },
+                authority_signer,
+            ),
+            None,
+        )?;
+
         
         let authority = &mut ctx.accounts.authority;
         authority.total_job_badges_minted += 1;
@@ -361,6 +1345,332 @@ pub mod badge_nft {
     }
 }
 
+fn create_soulbound_mint<'info>(
+    payer: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    mint_signer: &[&[&[u8]]],
+    permanent_delegate: &Pubkey,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let space = soulbound_mint_len()?;
+
+    create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: mint.clone(),
+            },
+            mint_signer,
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        token_program.key,
+    )?;
+
+    non_transferable_mint_initialize(CpiContext::new(
+        token_program.clone(),
+        NonTransferableMintInitialize {
+            token_program_id: token_program.clone(),
+            mint: mint.clone(),
+        },
+    ))?;
+
+    permanent_delegate_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            PermanentDelegateInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint.clone(),
+            },
+        ),
+        permanent_delegate,
+    )?;
+
+    initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 {
+                mint: mint.clone(),
+            },
+        ),
+        0,
+        mint.key,
+        Some(permanent_delegate),
+    )?;
+    Ok(())
+}
+
+fn soulbound_mint_len() -> Result<usize> {
+    Ok(ExtensionType::try_calculate_account_len::<Token2022Mint>(&[
+        ExtensionType::NonTransferable,
+        ExtensionType::PermanentDelegate,
+    ])?)
+}
+
+fn is_soulbound_mint(mint: &AccountInfo) -> Result<bool> {
+    Ok(*mint.owner == Token2022::id() && mint.data_len() == soulbound_mint_len()?)
+}
+
+fn validate_tier_bands(silver_min_score: u8, gold_min_score: u8) -> Result<()> {
+    require!(
+        silver_min_score >= PASSING_SCORE &&
+        gold_min_score >= silver_min_score &&
+        gold_min_score <= 100,
+        ErrorCode::InvalidTierBands
+    );
+    Ok(())
+}
+
+type Provenance = (Option<Vec<Creator>>, Option<Collection>);
+
+fn badge_provenance(authority: Pubkey, collection_mint: Pubkey) -> Provenance {
+    (
+        Some(vec![Creator {
+            address: authority,
+            verified: false,
+            share: 100,
+        }]),
+        Some(Collection {
+            verified: false,
+            key: collection_mint,
+        }),
+    )
+}
+
+fn existing_provenance(metadata: &AccountInfo) -> Result<Provenance> {
+    let metadata = MetadataAccount::try_deserialize(&mut &metadata.try_borrow_data()?[..])?;
+    Ok((metadata.creators.clone(), metadata.collection.clone()))
+}
+
+fn unverify_collection_item<'info>(
+    metadata: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_signer: &[&[&[u8]]],
+    payer: &AccountInfo<'info>,
+    collection_mint: &AccountInfo<'info>,
+    collection_metadata: &AccountInfo<'info>,
+    collection_master_edition: &AccountInfo<'info>,
+    metadata_program: &AccountInfo<'info>,
+) -> Result<Provenance> {
+    let (creators, mut collection) = existing_provenance(metadata)?;
+
+    if let Some(item) = collection.as_mut().filter(|c| c.verified) {
+        require!(item.key == collection_mint.key(), ErrorCode::CollectionMismatch);
+        unverify_sized_collection_item(
+            CpiContext::new_with_signer(
+                metadata_program.clone(),
+                UnverifySizedCollectionItem {
+                    metadata: metadata.clone(),
+                    collection_authority: authority.clone(),
+                    payer: payer.clone(),
+                    collection_mint: collection_mint.clone(),
+                    collection: collection_metadata.clone(),
+                    collection_master_edition_account: collection_master_edition.clone(),
+                },
+                authority_signer,
+            ),
+            None,
+        )?;
+        item.verified = false;
+    }
+
+    Ok((creators, collection))
+}
+
+fn badge_metadata(base_uri: &str, badge: &Badge, (creators, collection): Provenance) -> DataV2 {
+    let skill_category_str = badge.skill_category.to_string();
+    let metadata_uri = format!(
+        "{}/{}.json?score={}&tier={}&issued={}&expires={}",
+        base_uri,
+        skill_category_str.to_lowercase().replace(" ", "-"),
+        badge.test_score,
+        badge.tier.to_string().to_lowercase().replace(" ", "-"),
+        badge.issue_date,
+        badge.expiry_date
+    );
+    let mut data = DataV2 {
+        name: format!("CredChain {}", skill_category_str),
+        symbol: "CRED".to_string(),
+        uri: metadata_uri,
+        seller_fee_basis_points: 0,
+        creators,
+        collection,
+        uses: None,
+    };
+    if badge.revoked {
+        mark_revoked(&mut data);
+    }
+    data
+}
+
+fn job_badge_metadata(base_uri: &str, job_badge: &JobCompletionBadge, (creators, collection): Provenance) -> DataV2 {
+    let mut data = DataV2 {
+        name: truncate(&format!("CredChain Job: {}", job_badge.job_title), MAX_METADATA_NAME_LENGTH),
+        symbol: "CRED-JOB".to_string(),
+        uri: format!(
+            "{}/job-completion-template.json?contract={}&completed={}",
+            base_uri,
+            job_badge.contract_id,
+            job_badge.completion_date
+        ),
+        seller_fee_basis_points: 0,
+        creators,
+        collection,
+        uses: None,
+    };
+    if job_badge.revoked {
+        mark_revoked(&mut data);
+    }
+    data
+}
+
+fn mark_revoked(data: &mut DataV2) {
+    data.name = REVOKED_BADGE_NAME.to_string();
+    data.uri.push_str("&revoked=true");
+}
+
+fn truncate(value: &str, max_len: usize) -> String {
+    let mut end = value.len().min(max_len);
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    value[..end].to_string()
+}
+
+fn burn_badge_token<'info>(
+    authority: &AccountInfo<'info>,
+    authority_signer: &[&[&[u8]]],
+    mint: &AccountInfo<'info>,
+    token_account: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    burn(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            Burn {
+                mint: mint.clone(),
+                from: token_account.clone(),
+                authority: authority.clone(),
+            },
+            authority_signer,
+        ),
+        1,
+    )?;
+    Ok(())
+}
+
+fn update_badge_metadata<'info>(
+    metadata: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    mint_signer: &[&[&[u8]]],
+    metadata_program: &AccountInfo<'info>,
+    data: DataV2,
+) -> Result<()> {
+    require!(data.uri.len() <= MAX_METADATA_URI_LENGTH, ErrorCode::MetadataUriTooLong);
+
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            metadata_program.clone(),
+            UpdateMetadataAccountsV2 {
+                metadata: metadata.clone(),
+                update_authority: mint.clone(),
+            },
+            mint_signer,
+        ),
+        None,
+        Some(data),
+        None,
+        None,
+    )?;
+    Ok(())
+}
+
+fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let shortfall = rent.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    account.realloc(space, true)?;
+    Ok(())
+}
+
+fn answer_key_merkle_root(answer_key: &[u8], salt: &[u8; 32]) -> [u8; 32] {
+    let mut level: Vec<[u8; 32]> = answer_key.iter()
+        .enumerate()
+        .map(|(i, answer)| hashv(&[&[i as u8], &[*answer], salt.as_ref()]).to_bytes())
+        .collect();
+
+    while level.len() > 1 {
+        level = level.chunks(2)
+            .map(|pair| match pair {
+                [left, right] => hashv(&[left.as_ref(), right.as_ref()]).to_bytes(),
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+
+    level.first().copied().unwrap_or_default()
+}
+
+fn verify_ed25519_signature(
+    instructions: &AccountInfo,
+    expected_signer: &Pubkey,
+    expected_message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions)?;
+    require!(current_index > 0, ErrorCode::MissingSignatureInstruction);
+
+    let ix = load_instruction_at_checked((current_index - 1) as usize, instructions)?;
+    require!(ix.program_id == ed25519_program::ID, ErrorCode::MissingSignatureInstruction);
+
+    let data = &ix.data;
+    require!(data.len() >= 16 && data[0] == 1, ErrorCode::InvalidSignatureInstruction);
+
+    let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+    let signature_ix_index = read_u16(4);
+    let public_key_offset = read_u16(6) as usize;
+    let public_key_ix_index = read_u16(8);
+    let message_offset = read_u16(10) as usize;
+    let message_size = read_u16(12) as usize;
+    let message_ix_index = read_u16(14);
+
+    require!(
+        signature_ix_index == u16::MAX &&
+        public_key_ix_index == u16::MAX &&
+        message_ix_index == u16::MAX,
+        ErrorCode::InvalidSignatureInstruction
+    );
+    require!(
+        data.len() >= public_key_offset + 32 && data.len() >= message_offset + message_size,
+        ErrorCode::InvalidSignatureInstruction
+    );
+
+    let public_key = &data[public_key_offset..public_key_offset + 32];
+    let message = &data[message_offset..message_offset + message_size];
+
+    require!(public_key == expected_signer.as_ref(), ErrorCode::UnauthorizedGrader);
+    require!(message == expected_message, ErrorCode::AttestationMismatch);
+    Ok(())
+}
+
 
 
 #[derive(Accounts)]
@@ -379,108 +1689,788 @@ pub struct Initialize<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(skill_category: SkillCategory, _score: u8, _duration: i64, _proctored: bool, test_nonce: u64)]
-pub struct RecordTestCompletion<'info> {
+pub struct MigrateProgramAuthority<'info> {
+    
     #[account(
-        init,
-        payer = candidate,
-        space = 8 + TestResult::INIT_SPACE,
-        seeds = [
-            b"test-result",
-            candidate.key().as_ref(),
-            skill_category.to_string().as_bytes(),
-            &test_nonce.to_le_bytes()
-        ],
-        bump
+        mut,
+        seeds = [b"authority"],
+        bump,
+        owner = crate::ID
     )]
-    pub test_result: Account<'info, TestResult>,
+    pub authority: UncheckedAccount<'info>,
     #[account(mut)]
-    pub candidate: Signer<'info>,
+    pub admin: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(skill_category: SkillCategory)]
-pub struct MintBadge<'info> {
+pub struct MigrateTestResult<'info> {
+    
+    #[account(mut, owner = crate::ID)]
+    pub test_result: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateBadge<'info> {
+    
+    #[account(mut, owner = crate::ID)]
+    pub badge: UncheckedAccount<'info>,
     #[account(
-        mut,
-        seeds = [b"authority"],
-        bump = authority.bump
+        seeds = [b"tier-config", tier_config.skill_category.to_string().as_bytes()],
+        bump = tier_config.bump
+    )]
+    pub tier_config: Account<'info, TierConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateJobBadge<'info> {
+    
+    #[account(mut, owner = crate::ID)]
+    pub job_badge: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMetadataBaseUri<'info> {
+    #[account(
+        mut,
+        seeds = [b"authority"],
+        bump = authority.bump,
+        has_one = admin
+    )]
+    pub authority: Account<'info, ProgramAuthority>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(collection: BadgeCollection)]
+pub struct CreateBadgeCollection<'info> {
+    #[account(
+        seeds = [b"authority"],
+        bump = authority.bump,
+        has_one = admin
     )]
     pub authority: Account<'info, ProgramAuthority>,
 
+    #[account(
+        init,
+        payer = admin,
+        mint::decimals = 0,
+        mint::authority = authority,
+        mint::freeze_authority = authority,
+        mint::token_program = token_program,
+        seeds = [b"collection-mint", collection.seed().as_bytes()],
+        bump
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = collection_mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program
+    )]
+    pub collection_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    
     #[account(mut)]
-    pub test_result: Account<'info, TestResult>,
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    
+    #[account(mut)]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(issuer_id: String)]
+pub struct RegisterIssuer<'info> {
+    #[account(
+        seeds = [b"authority"],
+        bump = authority.bump,
+        has_one = admin
+    )]
+    pub authority: Account<'info, ProgramAuthority>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Issuer::INIT_SPACE,
+        seeds = [b"issuer", issuer_id.as_bytes()],
+        bump
+    )]
+    pub issuer: Account<'info, Issuer>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateIssuer<'info> {
+    #[account(
+        seeds = [b"authority"],
+        bump = authority.bump,
+        has_one = admin
+    )]
+    pub authority: Account<'info, ProgramAuthority>,
+    #[account(
+        mut,
+        seeds = [b"issuer", issuer.issuer_id.as_bytes()],
+        bump = issuer.bump
+    )]
+    pub issuer: Account<'info, Issuer>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(skill_category: SkillCategory)]
+pub struct InitializeTierConfig<'info> {
+    #[account(
+        seeds = [b"authority"],
+        bump = authority.bump,
+        has_one = admin
+    )]
+    pub authority: Account<'info, ProgramAuthority>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + TierConfig::INIT_SPACE,
+        seeds = [b"tier-config", skill_category.to_string().as_bytes()],
+        bump
+    )]
+    pub tier_config: Account<'info, TierConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTierConfig<'info> {
+    #[account(
+        seeds = [b"authority"],
+        bump = authority.bump,
+        has_one = admin
+    )]
+    pub authority: Account<'info, ProgramAuthority>,
+    #[account(
+        mut,
+        seeds = [b"tier-config", tier_config.skill_category.to_string().as_bytes()],
+        bump = tier_config.bump
+    )]
+    pub tier_config: Account<'info, TierConfig>,
+    pub admin: Signer<'info>,
+}
 
+#[derive(Accounts)]
+#[instruction(skill_category: SkillCategory)]
+pub struct InitializeAttemptTracker<'info> {
     #[account(
         init,
         payer = candidate,
-        space = 8 + Badge::INIT_SPACE,
+        space = 8 + AttemptTracker::INIT_SPACE,
         seeds = [
-            b"badge",
+            b"attempt-tracker",
             candidate.key().as_ref(),
             skill_category.to_string().as_bytes()
         ],
         bump
     )]
-    pub badge: Account<'info, Badge>,
+    pub attempt_tracker: Account<'info, AttemptTracker>,
+    #[account(mut)]
+    pub candidate: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(skill_category: SkillCategory, _score: u8, _duration: i64, _proctored: bool, test_nonce: u64)]
+pub struct RecordTestCompletion<'info> {
+    #[account(
+        mut,
+        seeds = [b"issuer", issuer.issuer_id.as_bytes()],
+        bump = issuer.bump
+    )]
+    pub issuer: Account<'info, Issuer>,
+    #[account(
+        init,
+        payer = candidate,
+        space = 8 + TestResult::INIT_SPACE,
+        seeds = [
+            b"test-result",
+            candidate.key().as_ref(),
+            skill_category.to_string().as_bytes(),
+            &test_nonce.to_le_bytes()
+        ],
+        bump
+    )]
+    pub test_result: Account<'info, TestResult>,
+    #[account(
+        mut,
+        seeds = [
+            b"attempt-tracker",
+            candidate.key().as_ref(),
+            skill_category.to_string().as_bytes()
+        ],
+        bump = attempt_tracker.bump
+    )]
+    pub attempt_tracker: Account<'info, AttemptTracker>,
+    #[account(mut)]
+    pub candidate: Signer<'info>,
+
+    
+    #[account(address = INSTRUCTIONS_SYSVAR_ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
 
+#[derive(Accounts)]
+#[instruction(skill_category: SkillCategory, session_nonce: u64)]
+pub struct CreateTestSession<'info> {
+    #[account(
+        seeds = [b"issuer", issuer.issuer_id.as_bytes()],
+        bump = issuer.bump,
+        constraint = issuer.signing_key == issuer_signer.key() @ ErrorCode::UnauthorizedGrader
+    )]
+    pub issuer: Account<'info, Issuer>,
     #[account(
         init,
         payer = candidate,
-        mint::decimals = 0,
-        mint::authority = mint,
-        mint::token_program = token_program,
+        space = 8 + TestSession::INIT_SPACE,
         seeds = [
-            b"badge-mint",
+            b"test-session",
+            candidate.key().as_ref(),
+            skill_category.to_string().as_bytes(),
+            &session_nonce.to_le_bytes()
+        ],
+        bump
+    )]
+    pub test_session: Account<'info, TestSession>,
+    #[account(
+        mut,
+        seeds = [
+            b"attempt-tracker",
             candidate.key().as_ref(),
             skill_category.to_string().as_bytes()
         ],
+        bump = attempt_tracker.bump
+    )]
+    pub attempt_tracker: Account<'info, AttemptTracker>,
+    #[account(mut)]
+    pub candidate: Signer<'info>,
+    pub issuer_signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitTestAnswers<'info> {
+    #[account(
+        mut,
+        has_one = candidate
+    )]
+    pub test_session: Account<'info, TestSession>,
+    pub candidate: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevealAnswerKey<'info> {
+    #[account(
+        mut,
+        seeds = [b"issuer", issuer.issuer_id.as_bytes()],
+        bump = issuer.bump,
+        constraint = issuer.signing_key == issuer_signer.key() @ ErrorCode::UnauthorizedGrader
+    )]
+    pub issuer: Account<'info, Issuer>,
+    #[account(
+        mut,
+        seeds = [
+            b"test-session",
+            test_session.candidate.as_ref(),
+            test_session.skill_category.to_string().as_bytes(),
+            &test_session.nonce.to_le_bytes()
+        ],
+        bump = test_session.bump,
+        has_one = issuer
+    )]
+    pub test_session: Account<'info, TestSession>,
+    #[account(
+        init,
+        payer = issuer_signer,
+        space = 8 + TestResult::INIT_SPACE,
+        seeds = [
+            b"test-result",
+            test_session.candidate.as_ref(),
+            test_session.skill_category.to_string().as_bytes(),
+            &test_session.nonce.to_le_bytes()
+        ],
         bump
     )]
-    pub mint: InterfaceAccount<'info, Mint>,
+    pub test_result: Account<'info, TestResult>,
+    #[account(
+        mut,
+        seeds = [
+            b"attempt-tracker",
+            test_session.candidate.as_ref(),
+            test_session.skill_category.to_string().as_bytes()
+        ],
+        bump = attempt_tracker.bump
+    )]
+    pub attempt_tracker: Account<'info, AttemptTracker>,
+    #[account(mut)]
+    pub issuer_signer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VoidTestSession<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"test-session",
+            test_session.candidate.as_ref(),
+            test_session.skill_category.to_string().as_bytes(),
+            &test_session.nonce.to_le_bytes()
+        ],
+        bump = test_session.bump,
+        has_one = candidate,
+        close = candidate
+    )]
+    pub test_session: Account<'info, TestSession>,
+    #[account(
+        mut,
+        seeds = [
+            b"attempt-tracker",
+            test_session.candidate.as_ref(),
+            test_session.skill_category.to_string().as_bytes()
+        ],
+        bump = attempt_tracker.bump
+    )]
+    pub attempt_tracker: Account<'info, AttemptTracker>,
+    #[account(mut)]
+    pub candidate: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(skill_category: SkillCategory)]
+pub struct MintBadge<'info> {
+    #[account(
+        mut,
+        seeds = [b"authority"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, ProgramAuthority>,
+
+    #[account(
+        mut,
+        has_one = candidate,
+        constraint = test_result.skill_category == skill_category @ ErrorCode::TestCategoryMismatch
+    )]
+    pub test_result: Account<'info, TestResult>,
+
+    #[account(
+        seeds = [b"tier-config", skill_category.to_string().as_bytes()],
+        bump = tier_config.bump
+    )]
+    pub tier_config: Account<'info, TierConfig>,
 
     #[account(
         init,
         payer = candidate,
-        associated_token::mint = mint,
-        associated_token::authority = candidate,
-        associated_token::token_program = token_program
+        space = 8 + Badge::INIT_SPACE,
+        seeds = [
+            b"badge",
+            candidate.key().as_ref(),
+            skill_category.to_string().as_bytes()
+        ],
+        bump
+    )]
+    pub badge: Account<'info, Badge>,
+
+    
+    #[account(
+        mut,
+        seeds = [
+            b"badge-mint",
+            candidate.key().as_ref(),
+            skill_category.to_string().as_bytes()
+        ],
+        bump
+    )]
+    pub mint: UncheckedAccount<'info>,
+
+    
+    #[account(mut)]
+    pub token_account: UncheckedAccount<'info>,
+
+    
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"collection-mint", skill_category.to_string().as_bytes()],
+        bump
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub candidate: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct RenewBadge<'info> {
+    #[account(
+        seeds = [b"authority"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, ProgramAuthority>,
+    #[account(
+        mut,
+        seeds = [
+            b"badge",
+            candidate.key().as_ref(),
+            badge.skill_category.to_string().as_bytes()
+        ],
+        bump = badge.bump,
+        constraint = badge.owner == candidate.key() @ ErrorCode::NotBadgeOwner
+    )]
+    pub badge: Account<'info, Badge>,
+    #[account(
+        mut,
+        constraint = test_result.candidate == candidate.key() @ ErrorCode::TestResultMismatch
+    )]
+    pub test_result: Account<'info, TestResult>,
+    #[account(
+        seeds = [b"tier-config", badge.skill_category.to_string().as_bytes()],
+        bump = tier_config.bump
+    )]
+    pub tier_config: Account<'info, TierConfig>,
+    #[account(
+        seeds = [
+            b"badge-mint",
+            candidate.key().as_ref(),
+            badge.skill_category.to_string().as_bytes()
+        ],
+        bump
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+    pub candidate: Signer<'info>,
+    pub metadata_program: Program<'info, Metadata>,
+}
+
+#[derive(Accounts)]
+pub struct UpgradeBadgeTier<'info> {
+    #[account(
+        seeds = [b"authority"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, ProgramAuthority>,
+    #[account(
+        mut,
+        seeds = [
+            b"badge",
+            candidate.key().as_ref(),
+            badge.skill_category.to_string().as_bytes()
+        ],
+        bump = badge.bump,
+        constraint = badge.owner == candidate.key() @ ErrorCode::NotBadgeOwner
+    )]
+    pub badge: Account<'info, Badge>,
+    #[account(
+        mut,
+        constraint = test_result.candidate == candidate.key() @ ErrorCode::TestResultMismatch
+    )]
+    pub test_result: Account<'info, TestResult>,
+    #[account(
+        seeds = [b"tier-config", badge.skill_category.to_string().as_bytes()],
+        bump = tier_config.bump
+    )]
+    pub tier_config: Account<'info, TierConfig>,
+    #[account(
+        seeds = [
+            b"badge-mint",
+            candidate.key().as_ref(),
+            badge.skill_category.to_string().as_bytes()
+        ],
+        bump
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    pub candidate: Signer<'info>,
+    pub metadata_program: Program<'info, Metadata>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyBadge<'info> {
+    pub badge: Account<'info, Badge>,
+    #[account(
+        token::mint = badge.mint,
+        token::token_program = token_program
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    pub verifier: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeBadge<'info> {
+    #[account(
+        mut,
+        seeds = [b"authority"],
+        bump = authority.bump,
+        has_one = admin
+    )]
+    pub authority: Account<'info, ProgramAuthority>,
+    #[account(mut)]
+    pub badge: Account<'info, Badge>,
+    #[account(
+        mut,
+        seeds = [
+            b"badge-mint",
+            badge.owner.as_ref(),
+            badge.skill_category.to_string().as_bytes()
+        ],
+        bump
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = token_account.owner == badge.owner @ ErrorCode::NotBadgeOwner
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"collection-mint", badge.skill_category.to_string().as_bytes()],
+        bump
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    
+    pub collection_master_edition: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub metadata_program: Program<'info, Metadata>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeJobBadge<'info> {
+    #[account(
+        seeds = [b"authority"],
+        bump = authority.bump,
+        has_one = admin
+    )]
+    pub authority: Account<'info, ProgramAuthority>,
+    #[account(
+        mut,
+        seeds = [
+            b"job-badge",
+            job_badge.freelancer.as_ref(),
+            job_badge.contract_id.as_bytes()
+        ],
+        bump = job_badge.bump
+    )]
+    pub job_badge: Account<'info, JobCompletionBadge>,
+    #[account(
+        mut,
+        seeds = [
+            b"job-badge-mint",
+            job_badge.freelancer.as_ref(),
+            job_badge.contract_id.as_bytes()
+        ],
+        bump
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = token_account.owner == job_badge.freelancer @ ErrorCode::NotBadgeOwner
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"collection-mint", BadgeCollection::JobCompletion.seed().as_bytes()],
+        bump
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    
+    pub collection_master_edition: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub token_program: Program<'info, Token2022>,
+    pub metadata_program: Program<'info, Metadata>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeLegacyBadge<'info> {
+    #[account(
+        seeds = [b"authority"],
+        bump = authority.bump,
+        has_one = admin
+    )]
+    pub authority: Account<'info, ProgramAuthority>,
+    #[account(mut)]
+    pub badge: Account<'info, Badge>,
+
+    
+    #[account(
+        seeds = [
+            b"badge-mint",
+            badge.owner.as_ref(),
+            badge.skill_category.to_string().as_bytes()
+        ],
+        bump
+    )]
+    pub mint: UncheckedAccount<'info>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeLegacyJobBadge<'info> {
+    #[account(
+        seeds = [b"authority"],
+        bump = authority.bump,
+        has_one = admin
+    )]
+    pub authority: Account<'info, ProgramAuthority>,
+    #[account(
+        mut,
+        seeds = [
+            b"job-badge",
+            job_badge.freelancer.as_ref(),
+            job_badge.contract_id.as_bytes()
+        ],
+        bump = job_badge.bump
+    )]
+    pub job_badge: Account<'info, JobCompletionBadge>,
+
+    
+    #[account(
+        seeds = [
+            b"job-badge-mint",
+            job_badge.freelancer.as_ref(),
+            job_badge.contract_id.as_bytes()
+        ],
+        bump
+    )]
+    pub mint: UncheckedAccount<'info>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RefreshBadgeMetadata<'info> {
+    #[account(
+        seeds = [b"authority"],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, ProgramAuthority>,
+    #[account(
+        seeds = [
+            b"badge",
+            badge.owner.as_ref(),
+            badge.skill_category.to_string().as_bytes()
+        ],
+        bump = badge.bump
+    )]
+    pub badge: Account<'info, Badge>,
+    #[account(
+        seeds = [
+            b"badge-mint",
+            badge.owner.as_ref(),
+            badge.skill_category.to_string().as_bytes()
+        ],
+        bump
     )]
-    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     
     #[account(mut)]
     pub metadata: UncheckedAccount<'info>,
-
-    #[account(mut)]
-    pub candidate: Signer<'info>,
-
-    pub token_program: Interface<'info, TokenInterface>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
     pub metadata_program: Program<'info, Metadata>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-}
-
-#[derive(Accounts)]
-pub struct VerifyBadge<'info> {
-    pub badge: Account<'info, Badge>,
-    pub verifier: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct RevokeBadge<'info> {
+pub struct RefreshJobBadgeMetadata<'info> {
     #[account(
-        mut,
         seeds = [b"authority"],
-        bump = authority.bump,
-        has_one = admin
+        bump = authority.bump
     )]
     pub authority: Account<'info, ProgramAuthority>,
+    #[account(
+        seeds = [
+            b"job-badge",
+            job_badge.freelancer.as_ref(),
+            job_badge.contract_id.as_bytes()
+        ],
+        bump = job_badge.bump
+    )]
+    pub job_badge: Account<'info, JobCompletionBadge>,
+    #[account(
+        seeds = [
+            b"job-badge-mint",
+            job_badge.freelancer.as_ref(),
+            job_badge.contract_id.as_bytes()
+        ],
+        bump
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    
     #[account(mut)]
-    pub badge: Account<'info, Badge>,
-    pub admin: Signer<'info>,
+    pub metadata: UncheckedAccount<'info>,
+    pub metadata_program: Program<'info, Metadata>,
 }
 
 #[derive(Accounts)]
@@ -494,6 +2484,11 @@ pub struct UpdateLeaderboard<'info> {
         bump
     )]
     pub leaderboard: Account<'info, Leaderboard>,
+    #[account(
+        has_one = candidate,
+        constraint = test_result.passed @ ErrorCode::TestNotPassed,
+        constraint = test_result.skill_category == skill_category @ ErrorCode::TestCategoryMismatch
+    )]
     pub test_result: Account<'info, TestResult>,
     #[account(mut)]
     pub candidate: Signer<'info>,
@@ -501,7 +2496,7 @@ pub struct UpdateLeaderboard<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(contract_id: String, job_title: String, contract_amount: u64)]
+#[instruction(contract_id: String)]
 pub struct MintJobCompletionBadge<'info> {
     #[account(
         mut,
@@ -510,6 +2505,13 @@ pub struct MintJobCompletionBadge<'info> {
     )]
     pub authority: Account<'info, ProgramAuthority>,
 
+    #[account(
+        seeds = [b"contract", contract_id.as_bytes()],
+        bump = contract.bump,
+        seeds::program = credchain_program.key()
+    )]
+    pub contract: Account<'info, CredchainContract>,
+
     #[account(
         init,
         payer = freelancer,
@@ -523,12 +2525,9 @@ pub struct MintJobCompletionBadge<'info> {
     )]
     pub job_badge: Account<'info, JobCompletionBadge>,
 
+    
     #[account(
-        init,
-        payer = freelancer,
-        mint::decimals = 0,
-        mint::authority = mint,
-        mint::token_program = token_program,
+        mut,
         seeds = [
             b"job-badge-mint",
             freelancer.key().as_ref(),
@@ -536,20 +2535,28 @@ pub struct MintJobCompletionBadge<'info> {
         ],
         bump
     )]
-    pub mint: InterfaceAccount<'info, Mint>,
+    pub mint: UncheckedAccount<'info>,
+
+    
+    #[account(mut)]
+    pub token_account: UncheckedAccount<'info>,
+
+    
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
 
     #[account(
-        init,
-        payer = freelancer,
-        associated_token::mint = mint,
-        associated_token::authority = freelancer,
-        associated_token::token_program = token_program
+        seeds = [b"collection-mint", BadgeCollection::JobCompletion.seed().as_bytes()],
+        bump
     )]
-    pub token_account: InterfaceAccount<'info, TokenAccount>,
+    pub collection_mint: InterfaceAccount<'info, Mint>,
 
     
     #[account(mut)]
-    pub metadata: UncheckedAccount<'info>,
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    
+    pub collection_master_edition: UncheckedAccount<'info>,
 
     #[account(mut)]
     pub freelancer: Signer<'info>,
@@ -557,7 +2564,8 @@ pub struct MintJobCompletionBadge<'info> {
     
     pub client: UncheckedAccount<'info>,
 
-    pub token_program: Interface<'info, TokenInterface>,
+    pub credchain_program: Program<'info, Credchain>,
+    pub token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub metadata_program: Program<'info, Metadata>,
     pub system_program: Program<'info, System>,
@@ -572,12 +2580,172 @@ pub struct ProgramAuthority {
     pub admin: Pubkey,
     pub total_badges_minted: u64,
     pub total_job_badges_minted: u64,
+    #[max_len(6)]
+    pub skill_base_uris: Vec<CategoryBaseUri>,
+    #[max_len(94)]
+    pub job_base_uri: String,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyProgramAuthority {
+    pub admin: Pubkey,
+    pub total_badges_minted: u64,
+    pub total_job_badges_minted: u64,
+    pub bump: u8,
+}
+
+impl ProgramAuthority {
+    pub fn base_uri(&self, collection: &BadgeCollection) -> String {
+        match collection {
+            BadgeCollection::Skill(skill_category) => self.skill_base_uris.iter()
+                .find(|e| &e.skill_category == skill_category)
+                .map(|e| e.base_uri.clone())
+                .unwrap_or_else(|| DEFAULT_METADATA_BASE_URI.to_string()),
+            BadgeCollection::JobCompletion => self.job_base_uri.clone(),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct CategoryBaseUri {
+    pub skill_category: SkillCategory,
+    #[max_len(94)]
+    pub base_uri: String,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Issuer {
+    #[max_len(32)]
+    pub issuer_id: String,
+    pub signing_key: Pubkey,
+    #[max_len(64)]
+    pub name: String,
+    #[max_len(6)]
+    pub categories: Vec<SkillCategory>,
+    pub quota: u32,
+    pub issued_count: u32,
+    pub status: IssuerStatus,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+impl Issuer {
+    pub fn record_issuance(&mut self, skill_category: &SkillCategory) -> Result<()> {
+        require!(self.status == IssuerStatus::Active, ErrorCode::IssuerNotActive);
+        require!(self.categories.contains(skill_category), ErrorCode::IssuerCategoryNotAllowed);
+        require!(self.issued_count < self.quota, ErrorCode::IssuerQuotaExceeded);
+        self.issued_count += 1;
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TierConfig {
+    pub skill_category: SkillCategory,
+    pub silver_min_score: u8,
+    pub gold_min_score: u8,
+    pub bump: u8,
+}
+
+impl TierConfig {
+    pub fn tier_for(&self, score: u8, proctored: bool) -> BadgeTier {
+        if score >= self.gold_min_score {
+            if proctored { BadgeTier::ProctoredGold } else { BadgeTier::Gold }
+        } else if score >= self.silver_min_score {
+            BadgeTier::Silver
+        } else {
+            BadgeTier::Bronze
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AttemptTracker {
+    pub candidate: Pubkey,
+    pub skill_category: SkillCategory,
+    pub total_attempts: u32,
+    pub failed_attempts: u32,
+    pub last_attempt_at: i64,
+    #[max_len(3)]
+    pub recent_attempts: Vec<i64>,
+    pub bump: u8,
+}
+
+impl AttemptTracker {
+    pub fn register_attempt(&mut self, now: i64) -> Result<()> {
+        require!(
+            self.total_attempts == 0 || now >= self.last_attempt_at + RETAKE_COOLDOWN,
+            ErrorCode::RetakeCooldownActive
+        );
+
+        self.recent_attempts.retain(|t| *t > now - ATTEMPT_WINDOW);
+        require!(
+            self.recent_attempts.len() < MAX_ATTEMPTS_PER_WINDOW,
+            ErrorCode::AttemptLimitReached
+        );
+
+        self.recent_attempts.push(now);
+        self.last_attempt_at = now;
+        self.total_attempts += 1;
+        Ok(())
+    }
+
+    pub fn refund_attempt(&mut self, started_at: i64) {
+        self.recent_attempts.retain(|t| *t != started_at);
+        if self.last_attempt_at == started_at {
+            self.last_attempt_at = 0;
+        }
+    }
+
+    pub fn record_outcome(&mut self, passed: bool) {
+        if !passed {
+            self.failed_attempts += 1;
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TestSession {
+    pub issuer: Pubkey,
+    pub candidate: Pubkey,
+    pub skill_category: SkillCategory,
+    pub nonce: u64,
+    pub answer_key_root: [u8; 32],
+    pub question_count: u8,
+    pub proctored: bool,
+    pub start_time: i64,
+    pub deadline: i64,
+    pub reveal_deadline: i64,
+    #[max_len(100)]
+    pub answers: Vec<u8>,
+    pub submitted: bool,
+    pub submitted_at: i64,
+    pub graded: bool,
     pub bump: u8,
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct TestResult {
+    pub candidate: Pubkey,
+    pub skill_category: SkillCategory,
+    pub score: u8,
+    pub passed: bool,
+    pub test_date: i64,
+    pub duration: i64,
+    pub proctored: bool,
+    pub badge_minted: bool,
+    pub issuer: Pubkey,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyTestResult {
     pub candidate: Pubkey,
     pub skill_category: SkillCategory,
     pub score: u8,
@@ -589,9 +2757,44 @@ pub struct TestResult {
     pub bump: u8,
 }
 
+impl TestResult {
+    pub fn from_legacy(legacy: LegacyTestResult) -> TestResult {
+        TestResult {
+            candidate: legacy.candidate,
+            skill_category: legacy.skill_category,
+            score: legacy.score,
+            passed: legacy.passed,
+            test_date: legacy.test_date,
+            duration: legacy.duration,
+            proctored: legacy.proctored,
+            badge_minted: legacy.badge_minted,
+            issuer: Pubkey::default(),
+            bump: legacy.bump,
+        }
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Badge {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub skill_category: SkillCategory,
+    pub issue_date: i64,
+    pub expiry_date: i64,
+    pub test_score: u8,
+    pub is_valid: bool,
+    pub revoked: bool,
+    pub issuer: Pubkey,
+    pub tier: BadgeTier,
+    pub renewal_count: u16,
+    #[max_len(5)]
+    pub renewal_history: Vec<BadgeRenewal>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyBadge {
     pub mint: Pubkey,
     pub owner: Pubkey,
     pub skill_category: SkillCategory,
@@ -603,6 +2806,34 @@ pub struct Badge {
     pub bump: u8,
 }
 
+impl Badge {
+    pub fn from_legacy(legacy: LegacyBadge, tier: BadgeTier) -> Badge {
+        Badge {
+            mint: legacy.mint,
+            owner: legacy.owner,
+            skill_category: legacy.skill_category,
+            issue_date: legacy.issue_date,
+            expiry_date: legacy.expiry_date,
+            test_score: legacy.test_score,
+            is_valid: legacy.is_valid,
+            revoked: legacy.revoked,
+            issuer: Pubkey::default(),
+            tier,
+            renewal_count: 0,
+            renewal_history: Vec::new(),
+            bump: legacy.bump,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct BadgeRenewal {
+    pub renewed_at: i64,
+    pub previous_expiry: i64,
+    pub previous_score: u8,
+    pub test_result: Pubkey,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Leaderboard {
@@ -634,11 +2865,57 @@ pub struct JobCompletionBadge {
     pub completion_date: i64,
     pub contract_amount: u64,
     pub is_valid: bool,
+    pub revoked: bool,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyJobCompletionBadge {
+    pub mint: Pubkey,
+    pub freelancer: Pubkey,
+    pub client: Pubkey,
+    pub contract_id: String,
+    pub job_title: String,
+    pub completion_date: i64,
+    pub contract_amount: u64,
+    pub is_valid: bool,
     pub bump: u8,
 }
 
+impl JobCompletionBadge {
+    pub fn from_legacy(legacy: LegacyJobCompletionBadge) -> JobCompletionBadge {
+        JobCompletionBadge {
+            mint: legacy.mint,
+            freelancer: legacy.freelancer,
+            client: legacy.client,
+            contract_id: legacy.contract_id,
+            job_title: legacy.job_title,
+            completion_date: legacy.completion_date,
+            contract_amount: legacy.contract_amount,
+            is_valid: legacy.is_valid,
+            revoked: false,
+            bump: legacy.bump,
+        }
+    }
+}
+
 
 
+pub const PASSING_SCORE: u8 = 70;
+pub const MAX_TEST_QUESTIONS: usize = 100;
+pub const ANSWER_KEY_REVEAL_WINDOW: i64 = 2 * 24 * 60 * 60;
+pub const RETAKE_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+pub const ATTEMPT_WINDOW: i64 = 90 * 24 * 60 * 60;
+pub const MAX_ATTEMPTS_PER_WINDOW: usize = 3;
+pub const BADGE_VALIDITY_PERIOD: i64 = 365 * 24 * 60 * 60;
+pub const MAX_RENEWAL_HISTORY: usize = 5;
+pub const MAX_METADATA_NAME_LENGTH: usize = 32;
+pub const MAX_METADATA_URI_LENGTH: usize = 200;
+pub const REVOKED_BADGE_NAME: &str = "Revoked CredChain Badge";
+pub const MAX_BASE_URI_LENGTH: usize = 94;
+pub const DEFAULT_METADATA_BASE_URI: &str =
+    "https://gateway.pinata.cloud/ipfs/bafybeiaxverp4nugxlsfj6a2waw5psojz763n7il5xx3apx57lfb6xuyqq";
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
 pub enum SkillCategory {
     SolanaDeveloper,
@@ -649,6 +2926,60 @@ pub enum SkillCategory {
     FrontendDeveloper,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub enum IssuerStatus {
+    Active,
+    Suspended,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum BadgeCollection {
+    Skill(SkillCategory),
+    JobCompletion,
+}
+
+impl BadgeCollection {
+    pub fn seed(&self) -> String {
+        match self {
+            BadgeCollection::Skill(skill_category) => skill_category.to_string(),
+            BadgeCollection::JobCompletion => "Job Completion".to_string(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            BadgeCollection::Skill(skill_category) => format!("{} Badges", skill_category.to_string()),
+            BadgeCollection::JobCompletion => "Job Badges".to_string(),
+        }
+    }
+
+    pub fn symbol(&self) -> String {
+        match self {
+            BadgeCollection::Skill(_) => "CRED".to_string(),
+            BadgeCollection::JobCompletion => "CRED-JOB".to_string(),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, PartialOrd, Ord, InitSpace, Debug)]
+pub enum BadgeTier {
+    Bronze,
+    Silver,
+    Gold,
+    ProctoredGold,
+}
+
+impl BadgeTier {
+    pub fn to_string(&self) -> String {
+        match self {
+            BadgeTier::Bronze => "Bronze".to_string(),
+            BadgeTier::Silver => "Silver".to_string(),
+            BadgeTier::Gold => "Gold".to_string(),
+            BadgeTier::ProctoredGold => "Proctored Gold".to_string(),
+        }
+    }
+}
+
 impl SkillCategory {
     pub fn to_string(&self) -> String {
         match self {
@@ -662,6 +2993,17 @@ impl SkillCategory {
     }
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TestAttestation {
+    pub candidate: Pubkey,
+    pub skill_category: SkillCategory,
+    pub score: u8,
+    pub duration: i64,
+    pub proctored: bool,
+    pub nonce: u64,
+    pub expiry: i64,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct VerificationResult {
     pub owner: Pubkey,
@@ -672,6 +3014,9 @@ pub struct VerificationResult {
     pub is_valid: bool,
     pub is_expired: bool,
     pub is_revoked: bool,
+    pub is_held_by_owner: bool,
+    pub issuer: Pubkey,
+    pub tier: BadgeTier,
 }
 
 
@@ -684,13 +3029,61 @@ pub struct TestCompletedEvent {
     pub passed: bool,
 }
 
+#[event]
+pub struct TestSessionCreatedEvent {
+    pub session: Pubkey,
+    pub candidate: Pubkey,
+    pub skill_category: SkillCategory,
+    pub deadline: i64,
+}
+
+#[event]
+pub struct TestSessionVoidedEvent {
+    pub session: Pubkey,
+    pub candidate: Pubkey,
+    pub skill_category: SkillCategory,
+}
+
 #[event]
 pub struct BadgeMintedEvent {
     pub candidate: Pubkey,
     pub skill_category: SkillCategory,
     pub mint: Pubkey,
     pub score: u8,
+    pub tier: BadgeTier,
+    pub expiry_date: i64,
+}
+
+#[event]
+pub struct BadgeTierUpgradedEvent {
+    pub badge: Pubkey,
+    pub owner: Pubkey,
+    pub skill_category: SkillCategory,
+    pub previous_tier: BadgeTier,
+    pub tier: BadgeTier,
+}
+
+#[event]
+pub struct BadgeCollectionCreatedEvent {
+    pub collection: BadgeCollection,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct BadgeRenewedEvent {
+    pub badge: Pubkey,
+    pub owner: Pubkey,
+    pub skill_category: SkillCategory,
+    pub score: u8,
     pub expiry_date: i64,
+    pub renewal_count: u16,
+}
+
+#[event]
+pub struct IssuerUpdatedEvent {
+    pub issuer: Pubkey,
+    pub signing_key: Pubkey,
+    pub status: IssuerStatus,
 }
 
 #[event]
@@ -708,6 +3101,14 @@ pub struct BadgeRevokedEvent {
     pub reason: String,
 }
 
+#[event]
+pub struct JobBadgeRevokedEvent {
+    pub job_badge: Pubkey,
+    pub freelancer: Pubkey,
+    pub contract_id: String,
+    pub reason: String,
+}
+
 #[event]
 pub struct JobBadgeMintedEvent {
     pub freelancer: Pubkey,
@@ -723,12 +3124,88 @@ pub struct JobBadgeMintedEvent {
 pub enum ErrorCode {
     #[msg("Invalid score (0-100)")]
     InvalidScore,
-    #[msg("Score too low (minimum 70%)")]
-    ScoreTooLow,
     #[msg("Test not passed")]
     TestNotPassed,
     #[msg("Badge already minted")]
     BadgeAlreadyMinted,
     #[msg("Badge already revoked")]
     BadgeAlreadyRevoked,
+    #[msg("Contract is not completed")]
+    ContractNotCompleted,
+    #[msg("Signer is not a freelancer on this contract")]
+    NotContractFreelancer,
+    #[msg("Client does not match the contract")]
+    ClientMismatch,
+    #[msg("Test attestation has expired")]
+    AttestationExpired,
+    #[msg("Missing Ed25519 signature instruction")]
+    MissingSignatureInstruction,
+    #[msg("Invalid Ed25519 signature instruction")]
+    InvalidSignatureInstruction,
+    #[msg("Attestation not signed by the issuer's signing key")]
+    UnauthorizedGrader,
+    #[msg("Signed attestation does not match the submitted result")]
+    AttestationMismatch,
+    #[msg("Issuer ID too long (max 32 chars)")]
+    IssuerIdTooLong,
+    #[msg("Issuer name too long (max 64 chars)")]
+    IssuerNameTooLong,
+    #[msg("Invalid issuer categories (1-6 required)")]
+    InvalidIssuerCategories,
+    #[msg("Issuer is not active")]
+    IssuerNotActive,
+    #[msg("Issuer is not accredited for this skill category")]
+    IssuerCategoryNotAllowed,
+    #[msg("Issuer quota exceeded")]
+    IssuerQuotaExceeded,
+    #[msg("Invalid question count (1-100)")]
+    InvalidQuestionCount,
+    #[msg("Time limit must be positive")]
+    InvalidTimeLimit,
+    #[msg("Test session is closed")]
+    TestSessionClosed,
+    #[msg("Test session is still open")]
+    TestSessionStillOpen,
+    #[msg("Test session already graded")]
+    SessionAlreadyGraded,
+    #[msg("Answer key reveal deadline has passed")]
+    RevealDeadlinePassed,
+    #[msg("Answer key reveal deadline has not passed")]
+    RevealDeadlineNotPassed,
+    #[msg("Answer count does not match the session")]
+    AnswerCountMismatch,
+    #[msg("Answer key does not match the committed root")]
+    AnswerKeyMismatch,
+    #[msg("Retake cooldown has not elapsed")]
+    RetakeCooldownActive,
+    #[msg("Maximum attempts reached for this window")]
+    AttemptLimitReached,
+    #[msg("Test nonce must match the next attempt number")]
+    InvalidAttemptNonce,
+    #[msg("Signer does not own this badge")]
+    NotBadgeOwner,
+    #[msg("Test result does not match this badge")]
+    TestResultMismatch,
+    #[msg("Test result is for a different skill category")]
+    TestCategoryMismatch,
+    #[msg("Test result predates the badge's last certification")]
+    StaleTestResult,
+    #[msg("Invalid tier score bands")]
+    InvalidTierBands,
+    #[msg("Badge has expired or is no longer valid; renew it first")]
+    BadgeExpired,
+    #[msg("Test result does not reach a higher tier")]
+    TierNotHigher,
+    #[msg("Metadata URI too long (max 200 chars)")]
+    MetadataUriTooLong,
+    #[msg("Invalid metadata base URI (1-94 chars)")]
+    InvalidBaseUri,
+    #[msg("Badge is verified into a different collection")]
+    CollectionMismatch,
+    #[msg("Signer is not the program admin")]
+    UnauthorizedAdmin,
+    #[msg("Account already uses the current layout")]
+    AccountAlreadyMigrated,
+    #[msg("Badge mint is a soulbound Token-2022 mint and must be burned on revoke")]
+    NotLegacyBadge,
 }
\ No newline at end of file
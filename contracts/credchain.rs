@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::system_program;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 declare_id!("J4cUiyURTW8woQCsc3YQwPPe2jMr8M27HFKWst468tUk");
@@ -18,8 +20,11 @@ pub mod credchain {
         total_amount: u64,
         milestones: Vec<MilestoneData>,
         payment_token: Pubkey,
+        nda_required: bool,
+        nda_hash: [u8; 32],
     ) -> Result<()> {
         require!(milestones.len() > 0 && milestones.len() <= 5, ErrorCode::InvalidMilestoneCount);
+        require!(!nda_required || nda_hash != [0u8; 32], ErrorCode::InvalidNdaHash);
 
         let contract = &mut ctx.accounts.contract;
         contract.contract_id = contract_id;
@@ -29,26 +34,31 @@ pub mod credchain {
         contract.freelancer = ctx.accounts.freelancer.key();
         contract.total_amount = total_amount;
         contract.paid_amount = 0;
+        contract.bonus_paid_amount = 0;
         contract.payment_token = payment_token;
         contract.status = ContractStatus::Active;
         contract.created_at = Clock::get()?.unix_timestamp;
         contract.nda_signed_client = false;
         contract.nda_signed_freelancer = false;
+        contract.nda_signed_client_at = 0;
+        contract.nda_signed_freelancer_at = 0;
+        contract.nda_required = nda_required;
+        contract.nda_hash = nda_hash;
+        contract.contributors = vec![];
         contract.bump = ctx.bumps.contract;
 
+        contract.parent_contract = None;
+        contract.parent_milestone_index = 0;
+        contract.contract_type = ContractType::FixedPrice;
+        contract.sequential = false;
+        contract.organization = None;
+        contract.delegates = vec![];
+        contract.job = None;
+        contract.time_session_count = 0;
+
         
         for (i, milestone_data) in milestones.iter().enumerate() {
-            contract.milestones.push(Milestone {
-                index: i as u8,
-                title: milestone_data.title.clone(),
-                description: milestone_data.description.clone(),
-                amount: milestone_data.amount,
-                deadline: milestone_data.deadline,
-                status: MilestoneStatus::Pending,
-                completed_at: 0,
-                revision_count: 0,
-                deliverables: vec![],
-            });
+            contract.milestones.push(Milestone::from_data(i as u8, milestone_data));
         }
 
         msg!("Contract created: {}", contract.contract_id);
@@ -56,127 +66,404 @@ pub mod credchain {
     }
 
     
-    pub fn deposit_escrow(
-        ctx: Context<DepositEscrow>,
-        amount: u64,
-    ) -> Result<()> {
+    pub fn link_job(ctx: Context<LinkJob>, _job_id: String) -> Result<()> {
+        let contract_key = ctx.accounts.contract.key();
         let contract = &mut ctx.accounts.contract;
+        require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
         require!(contract.status == ContractStatus::Active, ErrorCode::InvalidContractStatus);
-        require!(amount == contract.total_amount, ErrorCode::InvalidDepositAmount);
+        require!(contract.job.is_none(), ErrorCode::JobAlreadyLinked);
 
-        
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.client_token_account.to_account_info(),
-            to: ctx.accounts.escrow_token_account.to_account_info(),
-            authority: ctx.accounts.client.to_account_info(),
+        let job_info = ctx.accounts.job.to_account_info();
+        let job_data = job_info.try_borrow_data()?;
+        require!(
+            job_data.len() > 8 && job_data[..8] == hash(b"account:Job").to_bytes()[..8],
+            ErrorCode::InvalidJobAccount
+        );
+        let linked_job = JobBoardJob::deserialize(&mut &job_data[8..])?;
+        require!(linked_job.employer == contract.client, ErrorCode::InvalidJobAccount);
+        require!(
+            linked_job.selected_freelancer == Some(contract.freelancer),
+            ErrorCode::InvalidJobAccount
+        );
+        require!(linked_job.contract == Some(contract_key), ErrorCode::InvalidJobAccount);
+
+        let job = job_info.key();
+        contract.job = Some(job);
+
+        msg!("Contract {} linked to job {}", contract.contract_id, job);
+        Ok(())
+    }
+
+    
+    pub fn migrate_contract(ctx: Context<MigrateContract>) -> Result<()> {
+        let contract_info = ctx.accounts.contract.to_account_info();
+        let space = 8 + Contract::INIT_SPACE;
+        require!(contract_info.data_len() < space, ErrorCode::AccountAlreadyMigrated);
+
+        let legacy = {
+            let data = contract_info.try_borrow_data()?;
+            require!(data[..8] == Contract::DISCRIMINATOR[..], ErrorCode::AccountAlreadyMigrated);
+            LegacyContract::deserialize(&mut &data[8..])?
         };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::transfer(cpi_ctx, amount)?;
+        let contract = Contract::from_legacy(legacy);
 
-        contract.status = ContractStatus::Funded;
-        msg!("Escrow funded: {} tokens", amount);
+        resize_account(
+            &contract_info,
+            space,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        let mut data = contract_info.try_borrow_mut_data()?;
+        contract.try_serialize(&mut &mut data[..])?;
+
+        msg!("Contract migrated: {}", contract.contract_id);
         Ok(())
     }
 
     
-    pub fn sign_nda(ctx: Context<SignNDA>) -> Result<()> {
-        let contract = &mut ctx.accounts.contract;
-        let signer = ctx.accounts.signer.key();
+    pub fn migrate_dispute(ctx: Context<MigrateDispute>) -> Result<()> {
+        let dispute_info = ctx.accounts.dispute.to_account_info();
+        let space = 8 + Dispute::INIT_SPACE;
+        require!(dispute_info.data_len() < space, ErrorCode::AccountAlreadyMigrated);
 
-        if signer == contract.client {
-            contract.nda_signed_client = true;
-        } else if signer == contract.freelancer {
-            contract.nda_signed_freelancer = true;
+        let legacy = {
+            let data = dispute_info.try_borrow_data()?;
+            require!(data[..8] == Dispute::DISCRIMINATOR[..], ErrorCode::AccountAlreadyMigrated);
+            LegacyDispute::deserialize(&mut &data[8..])?
+        };
+        let contract = &ctx.accounts.contract;
+        require!(legacy.contract == contract.key(), ErrorCode::InvalidDisputeAccount);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let config = &ctx.accounts.config;
+        let contract_status = if ctx.accounts.escrow_token_account.amount > 0 {
+            ContractStatus::Funded
         } else {
-            return Err(ErrorCode::UnauthorizedSigner.into());
-        }
+            ContractStatus::Active
+        };
+        let phase_deadline = match legacy.status {
+            DisputeStatus::Open => current_time + config.evidence_period,
+            DisputeStatus::UnderReview => current_time + config.voting_period,
+            _ => 0,
+        };
+        let resolved_at = match legacy.status {
+            DisputeStatus::Open | DisputeStatus::UnderReview => 0,
+            _ => current_time,
+        };
 
-        msg!("NDA signed by: {}", signer);
+        let dispute = Dispute {
+            contract: legacy.contract,
+            initiator: legacy.initiator,
+            respondent: if legacy.initiator == contract.client {
+                contract.freelancer
+            } else {
+                contract.client
+            },
+            category: legacy.category,
+            reason: legacy.reason,
+            description: legacy.description,
+            status: legacy.status,
+            created_at: legacy.created_at,
+            phase_deadline,
+            evidence: vec![],
+            arbitrators: legacy.arbitrators,
+            votes: legacy.votes,
+            reassignment_count: 0,
+            resolved_at,
+            settlement: None,
+            contract_status,
+            bump: legacy.bump,
+        };
+
+        resize_account(
+            &dispute_info,
+            space,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        let mut data = dispute_info.try_borrow_mut_data()?;
+        dispute.try_serialize(&mut &mut data[..])?;
+
+        msg!("Dispute migrated for contract: {}", contract.contract_id);
         Ok(())
     }
 
     
-    pub fn submit_deliverable(
-        ctx: Context<SubmitDeliverable>,
-        milestone_index: u8,
-        ipfs_hash: String,
-        file_name: String,
+    pub fn create_subcontract(
+        ctx: Context<CreateSubcontract>,
+        contract_id: String,
+        title: String,
         description: String,
+        parent_milestone_index: u8,
+        milestones: Vec<MilestoneData>,
+        nda_required: bool,
+        nda_hash: [u8; 32],
     ) -> Result<()> {
+        require!(milestones.len() > 0 && milestones.len() <= 5, ErrorCode::InvalidMilestoneCount);
+        require!(!nda_required || nda_hash != [0u8; 32], ErrorCode::InvalidNdaHash);
+
+        let parent = &mut ctx.accounts.parent_contract;
+        require!(parent.status == ContractStatus::Funded, ErrorCode::InvalidContractStatus);
+        require!(
+            (parent_milestone_index as usize) < parent.milestones.len(),
+            ErrorCode::InvalidMilestoneIndex
+        );
+        require!(
+            parent.is_assigned(parent_milestone_index, &ctx.accounts.client.key()),
+            ErrorCode::UnauthorizedFreelancer
+        );
+
+        let parent_milestone = &parent.milestones[parent_milestone_index as usize];
+        require!(
+            parent_milestone.status == MilestoneStatus::Pending ||
+            parent_milestone.status == MilestoneStatus::RevisionRequested,
+            ErrorCode::InvalidMilestoneStatus
+        );
+
+        let total_amount = milestones
+            .iter()
+            .try_fold(0u64, |acc, m| acc.checked_add(m.amount))
+            .ok_or(ErrorCode::Overflow)?;
+        require!(
+            total_amount > 0 &&
+            total_amount <= parent_milestone.amount - parent_milestone.subcontracted_amount,
+            ErrorCode::SubcontractExceedsMilestone
+        );
+
+        let parent_id = parent.contract_id.clone();
+        let parent_bump = parent.bump;
+
+        let seeds = &[
+            b"contract",
+            parent_id.as_bytes(),
+            &[parent_bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.parent_escrow_token_account.to_account_info(),
+            to: ctx.accounts.escrow_token_account.to_account_info(),
+            authority: parent.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, total_amount)?;
+
+        let parent_milestone = &mut parent.milestones[parent_milestone_index as usize];
+        parent_milestone.subcontracted_amount += total_amount;
+        parent_milestone.open_subcontracts += 1;
+
         let contract = &mut ctx.accounts.contract;
-        require!(ctx.accounts.freelancer.key() == contract.freelancer, ErrorCode::UnauthorizedFreelancer);
-        require!((milestone_index as usize) < contract.milestones.len(), ErrorCode::InvalidMilestoneIndex);
+        contract.contract_id = contract_id;
+        contract.title = title;
+        contract.description = description;
+        contract.client = ctx.accounts.client.key();
+        contract.freelancer = ctx.accounts.freelancer.key();
+        contract.total_amount = total_amount;
+        contract.paid_amount = 0;
+        contract.bonus_paid_amount = 0;
+        contract.payment_token = parent.payment_token;
+        contract.status = ContractStatus::Funded;
+        contract.created_at = Clock::get()?.unix_timestamp;
+        contract.nda_signed_client = false;
+        contract.nda_signed_freelancer = false;
+        contract.nda_signed_client_at = 0;
+        contract.nda_signed_freelancer_at = 0;
+        contract.nda_required = nda_required;
+        contract.nda_hash = nda_hash;
+        contract.contributors = vec![];
+        contract.parent_contract = Some(parent.key());
+        contract.parent_milestone_index = parent_milestone_index;
+        contract.contract_type = ContractType::FixedPrice;
+        contract.sequential = false;
+        contract.organization = None;
+        contract.delegates = vec![];
+        contract.job = None;
+        contract.time_session_count = 0;
+        contract.bump = ctx.bumps.contract;
 
-        let milestone = &mut contract.milestones[milestone_index as usize];
-        require!(milestone.status == MilestoneStatus::Pending, ErrorCode::InvalidMilestoneStatus);
+        for (i, milestone_data) in milestones.iter().enumerate() {
+            contract.milestones.push(Milestone::from_data(i as u8, milestone_data));
+        }
 
-        milestone.deliverables.push(Deliverable {
-            ipfs_hash,
-            file_name,
-            description,
-            uploaded_at: Clock::get()?.unix_timestamp,
+        emit!(SubcontractCreatedEvent {
+            parent_contract_id: parent_id,
+            parent_milestone_index,
+            contract_id: contract.contract_id.clone(),
+            freelancer: contract.freelancer,
+            amount: total_amount,
         });
 
-        milestone.status = MilestoneStatus::UnderReview;
-        msg!("Deliverable submitted for milestone {}", milestone_index);
+        msg!("Subcontract created: {}", contract.contract_id);
         Ok(())
     }
 
     
-    pub fn request_revision(
-        ctx: Context<RequestRevision>,
-        milestone_index: u8,
-        reason: String,
+    pub fn settle_subcontract(ctx: Context<SettleSubcontract>) -> Result<()> {
+        let contract = &ctx.accounts.contract;
+        let parent = &mut ctx.accounts.parent_contract;
+
+        require!(
+            contract.parent_contract == Some(parent.key()),
+            ErrorCode::NotASubcontract
+        );
+        require!(
+            contract.status == ContractStatus::Completed || contract.status == ContractStatus::Cancelled,
+            ErrorCode::InvalidContractStatus
+        );
+
+        let parent_milestone = &mut parent.milestones[contract.parent_milestone_index as usize];
+        parent_milestone.open_subcontracts -= 1;
+
+        let contract = &mut ctx.accounts.contract;
+        contract.parent_contract = None;
+
+        msg!("Subcontract {} settled into parent {}", contract.contract_id, parent.contract_id);
+        Ok(())
+    }
+
+    
+    pub fn create_retainer_contract(
+        ctx: Context<CreateRetainerContract>,
+        contract_id: String,
+        title: String,
+        description: String,
+        period_amount: u64,
+        period_length: i64,
+        payment_token: Pubkey,
+        nda_required: bool,
+        nda_hash: [u8; 32],
     ) -> Result<()> {
+        require!(period_amount > 0, ErrorCode::InvalidDepositAmount);
+        require!(period_length > 0, ErrorCode::InvalidRetainerPeriod);
+        require!(!nda_required || nda_hash != [0u8; 32], ErrorCode::InvalidNdaHash);
+
+        let contract = &mut ctx.accounts.contract;
+        contract.contract_id = contract_id;
+        contract.title = title;
+        contract.description = description;
+        contract.client = ctx.accounts.client.key();
+        contract.freelancer = ctx.accounts.freelancer.key();
+        contract.total_amount = 0;
+        contract.paid_amount = 0;
+        contract.bonus_paid_amount = 0;
+        contract.payment_token = payment_token;
+        contract.status = ContractStatus::Active;
+        contract.created_at = Clock::get()?.unix_timestamp;
+        contract.nda_signed_client = false;
+        contract.nda_signed_freelancer = false;
+        contract.nda_signed_client_at = 0;
+        contract.nda_signed_freelancer_at = 0;
+        contract.nda_required = nda_required;
+        contract.nda_hash = nda_hash;
+        contract.milestones = vec![];
+        contract.contributors = vec![];
+        contract.parent_contract = None;
+        contract.parent_milestone_index = 0;
+        contract.contract_type = ContractType::Retainer;
+        contract.sequential = false;
+        contract.organization = None;
+        contract.delegates = vec![];
+        contract.job = None;
+        contract.time_session_count = 0;
+        contract.bump = ctx.bumps.contract;
+
+        let retainer = &mut ctx.accounts.retainer;
+        retainer.contract = contract.key();
+        retainer.period_amount = period_amount;
+        retainer.period_length = period_length;
+        retainer.period_index = 0;
+        retainer.period_start = 0;
+        retainer.funded_periods = 0;
+        retainer.objection_raised = false;
+        retainer.objection_reason = String::new();
+        retainer.notice_given_by = None;
+        retainer.bump = ctx.bumps.retainer;
+
+        msg!("Retainer contract created: {}", contract.contract_id);
+        Ok(())
+    }
+
+    
+    pub fn fund_retainer_period(ctx: Context<FundRetainerPeriod>, periods: u32) -> Result<()> {
         let contract = &mut ctx.accounts.contract;
+        let retainer = &mut ctx.accounts.retainer;
         require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
-        require!((milestone_index as usize) < contract.milestones.len(), ErrorCode::InvalidMilestoneIndex);
+        require!(contract.contract_type == ContractType::Retainer, ErrorCode::InvalidContractType);
+        require!(
+            contract.status == ContractStatus::Active || contract.status == ContractStatus::Funded,
+            ErrorCode::InvalidContractStatus
+        );
+        require!(retainer.notice_given_by.is_none(), ErrorCode::RetainerNoticeGiven);
+        require!(periods > 0, ErrorCode::InvalidRetainerPeriod);
+        require!(contract.nda_satisfied(), ErrorCode::NdaNotSigned);
 
-        
-        let contract_id = contract.contract_id.clone();
+        let amount = retainer
+            .period_amount
+            .checked_mul(periods as u64)
+            .ok_or(ErrorCode::Overflow)?;
 
-        let milestone = &mut contract.milestones[milestone_index as usize];
-        require!(milestone.status == MilestoneStatus::UnderReview, ErrorCode::InvalidMilestoneStatus);
-        require!(milestone.revision_count < 3, ErrorCode::MaxRevisionsReached);
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.client_token_account.to_account_info(),
+            to: ctx.accounts.escrow_token_account.to_account_info(),
+            authority: ctx.accounts.client.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
 
-        milestone.revision_count += 1;
-        milestone.status = MilestoneStatus::RevisionRequested;
-        let revision_count = milestone.revision_count;
+        if retainer.funded_periods == 0 {
+            let current_time = Clock::get()?.unix_timestamp;
+            let previous_end = if retainer.period_start == 0 {
+                0
+            } else {
+                retainer.period_start + retainer.period_length
+            };
+            retainer.period_start = previous_end.max(current_time);
+        }
+        let first_period_index = retainer.period_index + retainer.funded_periods;
+        retainer.funded_periods = retainer
+            .funded_periods
+            .checked_add(periods)
+            .ok_or(ErrorCode::Overflow)?;
 
-        emit!(RevisionRequestedEvent {
-            contract_id,
-            milestone_index,
-            reason,
-            revision_count,
+        contract.total_amount = contract.total_amount.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+        contract.status = ContractStatus::Funded;
+
+        emit!(RetainerPeriodFundedEvent {
+            contract_id: contract.contract_id.clone(),
+            period_index: first_period_index,
+            periods,
+            amount,
+            period_end: retainer.period_start + retainer.period_length * retainer.funded_periods as i64,
         });
 
-        msg!("Revision requested for milestone {}", milestone_index);
+        msg!("Retainer periods {}..{} funded", first_period_index, first_period_index + periods);
         Ok(())
     }
 
     
-    pub fn approve_milestone(
-        ctx: Context<ApproveMilestone>,
-        milestone_index: u8,
-    ) -> Result<()> {
+    pub fn claim_retainer_period(ctx: Context<ClaimRetainerPeriod>) -> Result<()> {
         let contract = &mut ctx.accounts.contract;
-        require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
+        let retainer = &mut ctx.accounts.retainer;
+        require!(ctx.accounts.freelancer.key() == contract.freelancer, ErrorCode::UnauthorizedFreelancer);
         require!(contract.status == ContractStatus::Funded, ErrorCode::InvalidContractStatus);
-        require!((milestone_index as usize) < contract.milestones.len(), ErrorCode::InvalidMilestoneIndex);
+        require!(retainer.funded_periods > 0, ErrorCode::RetainerPeriodNotFunded);
 
-        
-        let amount = contract.milestones[milestone_index as usize].amount;
+        let current_time = Clock::get()?.unix_timestamp;
+        let period_end = retainer.period_start + retainer.period_length;
+        require!(current_time >= period_end, ErrorCode::RetainerPeriodNotElapsed);
         require!(
-            contract.milestones[milestone_index as usize].status == MilestoneStatus::UnderReview,
-            ErrorCode::InvalidMilestoneStatus
+            !retainer.objection_raised || current_time >= period_end + RETAINER_OBJECTION_WINDOW,
+            ErrorCode::RetainerObjectionRaised
         );
 
-        
+        let amount = retainer.period_amount;
         let contract_id = contract.contract_id.clone();
         let contract_bump = contract.bump;
 
-        
         let seeds = &[
             b"contract",
             contract_id.as_bytes(),
@@ -193,212 +480,2671 @@ pub mod credchain {
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
         token::transfer(cpi_ctx, amount)?;
 
-        
-        contract.milestones[milestone_index as usize].status = MilestoneStatus::Completed;
-        contract.milestones[milestone_index as usize].completed_at = Clock::get()?.unix_timestamp;
         contract.paid_amount += amount;
 
-        
-        if contract.milestones.iter().all(|m| m.status == MilestoneStatus::Completed) {
-            contract.status = ContractStatus::Completed;
+        let period_index = retainer.period_index;
+        retainer.period_index += 1;
+        retainer.funded_periods -= 1;
+        retainer.objection_raised = false;
+        retainer.objection_reason = String::new();
+
+        if retainer.funded_periods > 0 {
+            retainer.period_start = period_end;
         }
 
-        emit!(MilestoneApprovedEvent {
+        contract.status = if retainer.funded_periods > 0 {
+            ContractStatus::Funded
+        } else if retainer.notice_given_by.is_some() {
+            ContractStatus::Completed
+        } else {
+            ContractStatus::Active
+        };
+
+        emit!(RetainerPeriodClaimedEvent {
             contract_id,
-            milestone_index,
+            period_index,
             amount,
         });
 
-        msg!("Milestone {} approved, {} tokens released", milestone_index, amount);
+        msg!("Retainer period {} claimed: {} tokens", period_index, amount);
         Ok(())
     }
 
     
-    pub fn open_dispute(
-        ctx: Context<OpenDispute>,
-        category: DisputeCategory,
+    pub fn raise_retainer_objection(
+        ctx: Context<RetainerObjection>,
         reason: String,
-        description: String,
     ) -> Result<()> {
-        let contract = &mut ctx.accounts.contract;
-        let dispute = &mut ctx.accounts.dispute;
-
+        let contract = &ctx.accounts.contract;
+        let retainer = &mut ctx.accounts.retainer;
+        require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
+        require!(retainer.funded_periods > 0, ErrorCode::RetainerPeriodNotFunded);
+        require!(!retainer.objection_raised, ErrorCode::RetainerObjectionRaised);
         require!(
-            ctx.accounts.initiator.key() == contract.client ||
-            ctx.accounts.initiator.key() == contract.freelancer,
-            ErrorCode::UnauthorizedDispute
+            Clock::get()?.unix_timestamp < retainer.period_start + retainer.period_length,
+            ErrorCode::RetainerObjectionWindowClosed
         );
 
-        dispute.contract = contract.key();
-        dispute.initiator = ctx.accounts.initiator.key();
-        dispute.category = category;
-        dispute.reason = reason;
-        dispute.description = description;
-        dispute.status = DisputeStatus::Open;
-        dispute.created_at = Clock::get()?.unix_timestamp;
-        dispute.arbitrators = vec![];
-        dispute.votes = vec![];
-        dispute.bump = ctx.bumps.dispute;
-
-        contract.status = ContractStatus::Disputed;
+        retainer.objection_raised = true;
+        retainer.objection_reason = reason.clone();
 
-        emit!(DisputeOpenedEvent {
+        emit!(RetainerObjectionEvent {
             contract_id: contract.contract_id.clone(),
-            initiator: dispute.initiator,
-            category: dispute.category.clone(),
+            period_index: retainer.period_index,
+            reason,
         });
 
-        msg!("Dispute opened");
+        msg!("Objection raised for retainer period {}", retainer.period_index);
         Ok(())
     }
 
     
-    pub fn submit_arbitrator_vote(
-        ctx: Context<SubmitArbitratorVote>,
-        vote_for_client: bool,
-        reasoning: String,
-    ) -> Result<()> {
-        let dispute = &mut ctx.accounts.dispute;
-        require!(dispute.status == DisputeStatus::UnderReview, ErrorCode::InvalidDisputeStatus);
+    pub fn withdraw_retainer_objection(ctx: Context<RetainerObjection>) -> Result<()> {
+        let contract = &ctx.accounts.contract;
+        let retainer = &mut ctx.accounts.retainer;
+        require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
+        require!(retainer.objection_raised, ErrorCode::NoRetainerObjection);
 
-        
-        require!(
-            dispute.arbitrators.contains(&ctx.accounts.arbitrator.key()),
-            ErrorCode::UnauthorizedArbitrator
-        );
+        retainer.objection_raised = false;
+        retainer.objection_reason = String::new();
 
-        
+        msg!("Objection withdrawn for retainer period {}", retainer.period_index);
+        Ok(())
+    }
+
+    
+    pub fn give_retainer_notice(ctx: Context<GiveRetainerNotice>) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        let retainer = &mut ctx.accounts.retainer;
+        let signer = ctx.accounts.signer.key();
         require!(
-            !dispute.votes.iter().any(|v| v.arbitrator == ctx.accounts.arbitrator.key()),
-            ErrorCode::AlreadyVoted
+            signer == contract.client || signer == contract.freelancer,
+            ErrorCode::UnauthorizedSigner
         );
+        require!(retainer.notice_given_by.is_none(), ErrorCode::RetainerNoticeGiven);
 
-        dispute.votes.push(ArbitratorVote {
-            arbitrator: ctx.accounts.arbitrator.key(),
-            vote_for_client,
-            reasoning,
-            voted_at: Clock::get()?.unix_timestamp,
-        });
+        retainer.notice_given_by = Some(signer);
 
-        
-        if dispute.votes.len() >= 2 {
-            let client_votes = dispute.votes.iter().filter(|v| v.vote_for_client).count();
-            dispute.status = if client_votes >= 2 {
-                DisputeStatus::ResolvedForClient
-            } else {
-                DisputeStatus::ResolvedForFreelancer
-            };
+        if retainer.funded_periods == 0 && contract.status == ContractStatus::Active {
+            contract.status = ContractStatus::Completed;
         }
 
-        msg!("Arbitrator vote submitted");
-        Ok(())
-    }
+        emit!(RetainerNoticeEvent {
+            contract_id: contract.contract_id.clone(),
+            given_by: signer,
+            final_period_index: retainer.period_index + retainer.funded_periods.saturating_sub(1),
+        });
+
+        msg!("Retainer notice given by: {}", signer);
+        Ok(())
+    }
+
+    
+    pub fn create_stream_contract(
+        ctx: Context<CreateStreamContract>,
+        contract_id: String,
+        title: String,
+        description: String,
+        amount: u64,
+        start_time: i64,
+        end_time: i64,
+        payment_token: Pubkey,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidDepositAmount);
+        require!(end_time > start_time, ErrorCode::InvalidStreamSchedule);
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.client_token_account.to_account_info(),
+            to: ctx.accounts.escrow_token_account.to_account_info(),
+            authority: ctx.accounts.client.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+
+        let contract = &mut ctx.accounts.contract;
+        contract.contract_id = contract_id;
+        contract.title = title;
+        contract.description = description;
+        contract.client = ctx.accounts.client.key();
+        contract.freelancer = ctx.accounts.freelancer.key();
+        contract.total_amount = amount;
+        contract.paid_amount = 0;
+        contract.bonus_paid_amount = 0;
+        contract.payment_token = payment_token;
+        contract.status = ContractStatus::Funded;
+        contract.created_at = Clock::get()?.unix_timestamp;
+        contract.nda_signed_client = false;
+        contract.nda_signed_freelancer = false;
+        contract.nda_signed_client_at = 0;
+        contract.nda_signed_freelancer_at = 0;
+        contract.nda_required = false;
+        contract.nda_hash = [0u8; 32];
+        contract.milestones = vec![];
+        contract.contributors = vec![];
+        contract.parent_contract = None;
+        contract.parent_milestone_index = 0;
+        contract.contract_type = ContractType::Stream;
+        contract.sequential = false;
+        contract.organization = None;
+        contract.delegates = vec![];
+        contract.job = None;
+        contract.time_session_count = 0;
+        contract.bump = ctx.bumps.contract;
+
+        let stream = &mut ctx.accounts.stream;
+        stream.contract = contract.key();
+        stream.deposited = amount;
+        stream.withdrawn = 0;
+        stream.start_time = start_time;
+        stream.end_time = end_time;
+        stream.stopped_at = 0;
+        stream.bump = ctx.bumps.stream;
+
+        emit!(StreamCreatedEvent {
+            contract_id: contract.contract_id.clone(),
+            amount,
+            start_time,
+            end_time,
+        });
+
+        msg!("Stream contract created: {}", contract.contract_id);
+        Ok(())
+    }
+
+    
+    pub fn withdraw_from_stream(ctx: Context<WithdrawFromStream>) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        let stream = &mut ctx.accounts.stream;
+        require!(ctx.accounts.freelancer.key() == contract.freelancer, ErrorCode::UnauthorizedFreelancer);
+        require!(contract.status == ContractStatus::Funded, ErrorCode::InvalidContractStatus);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let vested = stream.vested_amount(current_time);
+        let amount = vested - stream.withdrawn;
+        require!(amount > 0, ErrorCode::NothingToWithdraw);
+
+        let contract_id = contract.contract_id.clone();
+        let contract_bump = contract.bump;
+
+        let seeds = &[
+            b"contract",
+            contract_id.as_bytes(),
+            &[contract_bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            to: ctx.accounts.freelancer_token_account.to_account_info(),
+            authority: contract.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, amount)?;
+
+        stream.withdrawn += amount;
+        contract.paid_amount += amount;
+
+        if stream.withdrawn == stream.vested_amount(stream.end_time) {
+            contract.status = ContractStatus::Completed;
+        }
+
+        emit!(StreamWithdrawnEvent {
+            contract_id,
+            amount,
+            total_withdrawn: stream.withdrawn,
+        });
+
+        msg!("Withdrew {} tokens from stream", amount);
+        Ok(())
+    }
+
+    
+    pub fn stop_stream(ctx: Context<StopStream>) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        let stream = &mut ctx.accounts.stream;
+        require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
+        require!(contract.status == ContractStatus::Funded, ErrorCode::InvalidContractStatus);
+        require!(stream.stopped_at == 0, ErrorCode::StreamAlreadyStopped);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(current_time < stream.end_time, ErrorCode::StreamAlreadyEnded);
+
+        let vested = stream.vested_amount(current_time);
+        let refund = stream.deposited - vested;
+
+        let contract_id = contract.contract_id.clone();
+        let contract_bump = contract.bump;
+
+        let seeds = &[
+            b"contract",
+            contract_id.as_bytes(),
+            &[contract_bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        if refund > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.client_token_account.to_account_info(),
+                authority: contract.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            token::transfer(cpi_ctx, refund)?;
+        }
+
+        stream.stopped_at = current_time;
+        contract.total_amount = vested;
+
+        if stream.withdrawn == vested {
+            contract.status = ContractStatus::Completed;
+        }
+
+        emit!(StreamStoppedEvent {
+            contract_id,
+            vested,
+            refunded: refund,
+        });
+
+        msg!("Stream stopped: {} vested, {} refunded", vested, refund);
+        Ok(())
+    }
+
+    
+    pub fn deposit_escrow(
+        ctx: Context<DepositEscrow>,
+        amount: u64,
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        require!(contract.contract_type == ContractType::FixedPrice, ErrorCode::InvalidContractType);
+        require!(contract.status == ContractStatus::Active, ErrorCode::InvalidContractStatus);
+        require!(amount == contract.total_amount, ErrorCode::InvalidDepositAmount);
+        require!(contract.nda_satisfied(), ErrorCode::NdaNotSigned);
+
+        
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.client_token_account.to_account_info(),
+            to: ctx.accounts.escrow_token_account.to_account_info(),
+            authority: ctx.accounts.client.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+
+        contract.status = ContractStatus::Funded;
+        msg!("Escrow funded: {} tokens", amount);
+        Ok(())
+    }
+
+    
+    pub fn sign_nda(ctx: Context<SignNDA>, nda_hash: [u8; 32]) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        let signer = ctx.accounts.signer.key();
+        require!(nda_hash == contract.nda_hash, ErrorCode::InvalidNdaHash);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        if signer == contract.client {
+            contract.nda_signed_client = true;
+            contract.nda_signed_client_at = current_time;
+        } else if signer == contract.freelancer {
+            contract.nda_signed_freelancer = true;
+            contract.nda_signed_freelancer_at = current_time;
+        } else {
+            return Err(ErrorCode::UnauthorizedSigner.into());
+        }
+
+        emit!(NdaSignatureEvent {
+            contract_id: contract.contract_id.clone(),
+            signer,
+            signed: true,
+            timestamp: current_time,
+        });
+
+        msg!("NDA signed by: {}", signer);
+        Ok(())
+    }
+
+    
+    pub fn revoke_nda_signature(ctx: Context<SignNDA>) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        let signer = ctx.accounts.signer.key();
+        require!(contract.status == ContractStatus::Active, ErrorCode::NdaWorkStarted);
+        require!(
+            contract.milestones.iter().all(|m| m.deliverables.is_empty()),
+            ErrorCode::NdaWorkStarted
+        );
+        require!(contract.time_session_count == 0, ErrorCode::NdaWorkStarted);
+        require!(contract.paid_amount == 0, ErrorCode::NdaWorkStarted);
+
+        if signer == contract.client {
+            require!(contract.nda_signed_client, ErrorCode::NdaNotSigned);
+            contract.nda_signed_client = false;
+            contract.nda_signed_client_at = 0;
+        } else if signer == contract.freelancer {
+            require!(contract.nda_signed_freelancer, ErrorCode::NdaNotSigned);
+            contract.nda_signed_freelancer = false;
+            contract.nda_signed_freelancer_at = 0;
+        } else {
+            return Err(ErrorCode::UnauthorizedSigner.into());
+        }
+
+        emit!(NdaSignatureEvent {
+            contract_id: contract.contract_id.clone(),
+            signer,
+            signed: false,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("NDA signature revoked by: {}", signer);
+        Ok(())
+    }
+
+    
+    pub fn configure_team(
+        ctx: Context<ConfigureTeam>,
+        contributors: Vec<Contributor>,
+        milestone_shares: Vec<MilestoneShares>,
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
+        require!(contract.status == ContractStatus::Active, ErrorCode::InvalidContractStatus);
+        require!(contributors.len() > 0 && contributors.len() <= 5, ErrorCode::InvalidContributorCount);
+        require!(
+            contributors.iter().any(|c| c.wallet == contract.freelancer),
+            ErrorCode::InvalidContributorShares
+        );
+        Contributor::validate_shares(&contributors)?;
+
+        for milestone in contract.milestones.iter_mut() {
+            milestone.contributor_shares = vec![];
+        }
+
+        for entry in milestone_shares.iter() {
+            require!(
+                (entry.milestone_index as usize) < contract.milestones.len(),
+                ErrorCode::InvalidMilestoneIndex
+            );
+            require!(
+                entry.shares.len() > 0 && entry.shares.len() <= 5,
+                ErrorCode::InvalidContributorCount
+            );
+            require!(
+                entry.shares.iter().all(|s| contributors.iter().any(|c| c.wallet == s.wallet)),
+                ErrorCode::UnknownContributor
+            );
+            Contributor::validate_shares(&entry.shares)?;
+
+            contract.milestones[entry.milestone_index as usize].contributor_shares = entry.shares.clone();
+        }
+
+        contract.contributors = contributors;
+
+        emit!(TeamConfiguredEvent {
+            contract_id: contract.contract_id.clone(),
+            contributors: contract.contributors.iter().map(|c| c.wallet).collect(),
+        });
+
+        msg!("Team configured with {} contributors", contract.contributors.len());
+        Ok(())
+    }
+
+    
+    pub fn configure_milestone_ordering(
+        ctx: Context<ConfigureMilestoneOrdering>,
+        sequential: bool,
+        dependencies: Vec<MilestoneDependency>,
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
+        require!(contract.status == ContractStatus::Active, ErrorCode::InvalidContractStatus);
+
+        for milestone in contract.milestones.iter_mut() {
+            milestone.depends_on = vec![];
+        }
+
+        let milestone_count = contract.milestones.len();
+        for dependency in dependencies.iter() {
+            require!(
+                (dependency.milestone_index as usize) < milestone_count,
+                ErrorCode::InvalidMilestoneIndex
+            );
+            require!(
+                dependency.depends_on.len() < milestone_count &&
+                dependency.depends_on.iter().all(|d| {
+                    (*d as usize) < milestone_count && *d != dependency.milestone_index
+                }),
+                ErrorCode::InvalidMilestoneDependency
+            );
+
+            contract.milestones[dependency.milestone_index as usize].depends_on = dependency.depends_on.clone();
+        }
+
+        require!(!contract.has_dependency_cycle(), ErrorCode::InvalidMilestoneDependency);
+
+        contract.sequential = sequential;
+
+        msg!("Milestone ordering configured (sequential: {})", sequential);
+        Ok(())
+    }
+
+    
+    pub fn submit_deliverable(
+        ctx: Context<SubmitDeliverable>,
+        milestone_index: u8,
+        ipfs_hash: String,
+        file_name: String,
+        description: String,
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        contract.validate_submission(milestone_index, &ctx.accounts.freelancer.key())?;
+
+        let milestone = &mut contract.milestones[milestone_index as usize];
+        require!(!milestone.encrypted, ErrorCode::EncryptedMilestone);
+
+        milestone.deliverables.push(Deliverable {
+            ipfs_hash,
+            file_name,
+            description,
+            uploaded_at: Clock::get()?.unix_timestamp,
+        });
+
+        milestone.status = MilestoneStatus::UnderReview;
+        msg!("Deliverable submitted for milestone {}", milestone_index);
+        Ok(())
+    }
+
+    
+    pub fn request_revision(
+        ctx: Context<RequestRevision>,
+        milestone_index: u8,
+        reason: String,
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        let reviewer = ctx.accounts.client.key();
+        let is_org_reviewer = match &ctx.accounts.organization {
+            Some(organization) => {
+                contract.organization == Some(organization.key()) &&
+                organization.role_of(&reviewer).is_some()
+            }
+            None => false,
+        };
+        let is_delegate = contract
+            .delegates
+            .iter()
+            .any(|d| d.wallet == reviewer && d.role == DelegateRole::Reviewer);
+        require!(
+            reviewer == contract.client || is_org_reviewer || is_delegate,
+            ErrorCode::UnauthorizedClient
+        );
+        require!((milestone_index as usize) < contract.milestones.len(), ErrorCode::InvalidMilestoneIndex);
+
+        
+        let contract_id = contract.contract_id.clone();
+
+        let milestone = &mut contract.milestones[milestone_index as usize];
+        require!(milestone.status == MilestoneStatus::UnderReview, ErrorCode::InvalidMilestoneStatus);
+        require!(milestone.revision_count < 3, ErrorCode::MaxRevisionsReached);
+
+        milestone.revision_count += 1;
+        milestone.status = MilestoneStatus::RevisionRequested;
+        let revision_count = milestone.revision_count;
+
+        emit!(RevisionRequestedEvent {
+            contract_id,
+            milestone_index,
+            reason,
+            revision_count,
+            requested_by: reviewer,
+        });
+
+        msg!("Revision requested for milestone {}", milestone_index);
+        Ok(())
+    }
+
+    
+    pub fn grant_delegate(
+        ctx: Context<ManageDelegate>,
+        wallet: Pubkey,
+        role: DelegateRole,
+        spending_limit: u64,
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
+        require!(
+            wallet != contract.client && wallet != contract.freelancer,
+            ErrorCode::InvalidDelegate
+        );
+
+        if let Some(delegate) = contract.delegates.iter_mut().find(|d| d.wallet == wallet) {
+            delegate.role = role.clone();
+            delegate.spending_limit = spending_limit;
+        } else {
+            require!(contract.delegates.len() < 3, ErrorCode::InvalidDelegate);
+            contract.delegates.push(Delegate {
+                wallet,
+                role: role.clone(),
+                spending_limit,
+                spent: 0,
+            });
+        }
+
+        emit!(DelegateUpdatedEvent {
+            contract_id: contract.contract_id.clone(),
+            delegate: wallet,
+            role: Some(role),
+            spending_limit,
+        });
+
+        msg!("Delegate granted: {}", wallet);
+        Ok(())
+    }
+
+    
+    pub fn revoke_delegate(
+        ctx: Context<ManageDelegate>,
+        wallet: Pubkey,
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
+        require!(
+            contract.delegates.iter().any(|d| d.wallet == wallet),
+            ErrorCode::InvalidDelegate
+        );
+
+        contract.delegates.retain(|d| d.wallet != wallet);
+
+        emit!(DelegateUpdatedEvent {
+            contract_id: contract.contract_id.clone(),
+            delegate: wallet,
+            role: None,
+            spending_limit: 0,
+        });
+
+        msg!("Delegate revoked: {}", wallet);
+        Ok(())
+    }
+
+    
+    pub fn approve_milestone<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveMilestone<'info>>,
+        milestone_index: u8,
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        let approver = ctx.accounts.client.key();
+        require!(contract.organization.is_none(), ErrorCode::OrgApprovalRequired);
+        require!((milestone_index as usize) < contract.milestones.len(), ErrorCode::InvalidMilestoneIndex);
+
+        if approver != contract.client {
+            let milestone = &contract.milestones[milestone_index as usize];
+            let amount = milestone.amount - milestone.subcontracted_amount + milestone.conditional_bonus;
+
+            let delegate = contract
+                .delegates
+                .iter_mut()
+                .find(|d| d.wallet == approver && d.role == DelegateRole::Approver)
+                .ok_or(ErrorCode::UnauthorizedClient)?;
+            let spent = delegate.spent.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+            require!(spent <= delegate.spending_limit, ErrorCode::DelegateLimitExceeded);
+            delegate.spent = spent;
+        }
+
+        process_milestone_approval(
+            contract,
+            milestone_index,
+            approver,
+            &ctx.accounts.escrow_token_account,
+            ctx.accounts.freelancer_token_account.as_ref(),
+            &ctx.accounts.token_program,
+            ctx.remaining_accounts,
+        )
+    }
+
+    
+    pub fn submit_encrypted_deliverable(
+        ctx: Context<SubmitDeliverable>,
+        milestone_index: u8,
+        ipfs_hash: String,
+        file_name: String,
+        description: String,
+        key_commitment: [u8; 32],
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        contract.validate_submission(milestone_index, &ctx.accounts.freelancer.key())?;
+
+        let milestone = &mut contract.milestones[milestone_index as usize];
+        require!(
+            !milestone.encrypted || milestone.key_commitment == key_commitment,
+            ErrorCode::KeyCommitmentMismatch
+        );
+
+        milestone.deliverables.push(Deliverable {
+            ipfs_hash,
+            file_name,
+            description,
+            uploaded_at: Clock::get()?.unix_timestamp,
+        });
+
+        milestone.encrypted = true;
+        milestone.key_commitment = key_commitment;
+        milestone.status = MilestoneStatus::UnderReview;
+        msg!("Encrypted deliverable submitted for milestone {}", milestone_index);
+        Ok(())
+    }
+
+    
+    pub fn reveal_deliverable_key<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevealDeliverableKey<'info>>,
+        milestone_index: u8,
+        decryption_key: [u8; 32],
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        require!(contract.status == ContractStatus::Funded, ErrorCode::InvalidContractStatus);
+        require!((milestone_index as usize) < contract.milestones.len(), ErrorCode::InvalidMilestoneIndex);
+        require!(
+            contract.is_assigned(milestone_index, &ctx.accounts.freelancer.key()),
+            ErrorCode::UnauthorizedFreelancer
+        );
+
+        let milestone = &mut contract.milestones[milestone_index as usize];
+        require!(milestone.status == MilestoneStatus::AwaitingKey, ErrorCode::InvalidMilestoneStatus);
+
+        if hash(&decryption_key).to_bytes() != milestone.key_commitment {
+            milestone.status = MilestoneStatus::KeyMismatch;
+
+            emit!(KeyMismatchEvent {
+                contract_id: contract.contract_id.clone(),
+                milestone_index,
+                revealed_by: ctx.accounts.freelancer.key(),
+            });
+
+            msg!("Decryption key mismatch on milestone {}, flagged for dispute", milestone_index);
+            return Ok(());
+        }
+
+        milestone.decryption_key = Some(decryption_key);
+
+        emit!(KeyRevealedEvent {
+            contract_id: contract.contract_id.clone(),
+            milestone_index,
+            decryption_key,
+        });
+
+        release_milestone_payment(
+            contract,
+            milestone_index,
+            &ctx.accounts.escrow_token_account,
+            ctx.accounts.freelancer_token_account.as_ref(),
+            &ctx.accounts.token_program,
+            ctx.remaining_accounts,
+        )
+    }
+
+    
+    pub fn escrow_conditional_bonus(
+        ctx: Context<EscrowConditionalBonus>,
+        milestone_index: u8,
+        amount: u64,
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
+        require!(contract.status == ContractStatus::Funded, ErrorCode::InvalidContractStatus);
+        require!((milestone_index as usize) < contract.milestones.len(), ErrorCode::InvalidMilestoneIndex);
+        require!(amount > 0, ErrorCode::InvalidBonusAmount);
+
+        let milestone = &contract.milestones[milestone_index as usize];
+        require!(milestone.status != MilestoneStatus::Completed, ErrorCode::InvalidMilestoneStatus);
+        require!(Clock::get()?.unix_timestamp <= milestone.deadline, ErrorCode::MilestoneDeadlinePassed);
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.client_token_account.to_account_info(),
+            to: ctx.accounts.escrow_token_account.to_account_info(),
+            authority: ctx.accounts.client.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+
+        contract.milestones[milestone_index as usize].conditional_bonus += amount;
+
+        msg!("Conditional bonus of {} escrowed for milestone {}", amount, milestone_index);
+        Ok(())
+    }
+
+    
+    pub fn reclaim_conditional_bonus(
+        ctx: Context<ReclaimConditionalBonus>,
+        milestone_index: u8,
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
+        require!((milestone_index as usize) < contract.milestones.len(), ErrorCode::InvalidMilestoneIndex);
+
+        let milestone = &contract.milestones[milestone_index as usize];
+        require!(milestone.conditional_bonus > 0, ErrorCode::InvalidBonusAmount);
+        require!(
+            milestone.status == MilestoneStatus::Completed ||
+            (milestone.status == MilestoneStatus::Pending && Clock::get()?.unix_timestamp > milestone.deadline),
+            ErrorCode::InvalidMilestoneStatus
+        );
+        let amount = milestone.conditional_bonus;
+
+        let contract_id = contract.contract_id.clone();
+        let contract_bump = contract.bump;
+
+        let seeds = &[
+            b"contract",
+            contract_id.as_bytes(),
+            &[contract_bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            to: ctx.accounts.client_token_account.to_account_info(),
+            authority: contract.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, amount)?;
+
+        contract.milestones[milestone_index as usize].conditional_bonus = 0;
+
+        msg!("Conditional bonus of {} reclaimed for milestone {}", amount, milestone_index);
+        Ok(())
+    }
+
+    
+    pub fn pay_milestone_bonus<'info>(
+        ctx: Context<'_, '_, '_, 'info, PayMilestoneBonus<'info>>,
+        milestone_index: u8,
+        amount: u64,
+    ) -> Result<()> {
+        let contract = &ctx.accounts.contract;
+        require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
+        require!((milestone_index as usize) < contract.milestones.len(), ErrorCode::InvalidMilestoneIndex);
+        require!(amount > 0, ErrorCode::InvalidBonusAmount);
+        require!(
+            contract.milestones[milestone_index as usize].status == MilestoneStatus::Completed,
+            ErrorCode::InvalidMilestoneStatus
+        );
+
+        let shares = contract.payout_shares(milestone_index).to_vec();
+        let payouts = distribute_share(
+            contract.freelancer,
+            &shares,
+            amount,
+            ctx.accounts.client_token_account.to_account_info(),
+            ctx.accounts.client.to_account_info(),
+            &[],
+            ctx.accounts.freelancer_token_account.as_ref(),
+            &ctx.accounts.token_program,
+            ctx.remaining_accounts,
+        )?;
+        for (share, payout) in shares.iter().zip(payouts.iter()) {
+            emit!(ContributorPaidEvent {
+                contract_id: contract.contract_id.clone(),
+                milestone_index,
+                contributor: share.wallet,
+                amount: *payout,
+            });
+        }
+
+        let contract = &mut ctx.accounts.contract;
+        contract.milestones[milestone_index as usize].bonus_paid += amount;
+        contract.bonus_paid_amount += amount;
+
+        emit!(BonusPaidEvent {
+            contract_id: contract.contract_id.clone(),
+            milestone_index,
+            freelancer: contract.freelancer,
+            amount,
+            conditional: false,
+        });
+
+        msg!("Bonus of {} paid for milestone {}", amount, milestone_index);
+        Ok(())
+    }
+
+    
+    pub fn create_organization(
+        ctx: Context<CreateOrganization>,
+        org_id: String,
+        name: String,
+        members: Vec<OrgMember>,
+        threshold: u8,
+    ) -> Result<()> {
+        let creator = ctx.accounts.creator.key();
+        require!(
+            members.iter().any(|m| m.wallet == creator && m.role == OrgRole::Admin),
+            ErrorCode::UnauthorizedOrgMember
+        );
+        Organization::validate_members(&members, threshold)?;
+
+        let organization = &mut ctx.accounts.organization;
+        organization.org_id = org_id;
+        organization.name = name;
+        organization.members = members;
+        organization.threshold = threshold;
+        organization.update_nonce = 0;
+        organization.created_at = Clock::get()?.unix_timestamp;
+        organization.bump = ctx.bumps.organization;
+
+        msg!("Organization created: {}", organization.org_id);
+        Ok(())
+    }
+
+    
+    pub fn propose_org_update(
+        ctx: Context<ProposeOrgUpdate>,
+        members: Vec<OrgMember>,
+        threshold: u8,
+    ) -> Result<()> {
+        let organization = &mut ctx.accounts.organization;
+        let admin = ctx.accounts.admin.key();
+        require!(
+            organization.role_of(&admin) == Some(OrgRole::Admin),
+            ErrorCode::UnauthorizedOrgMember
+        );
+        require!(
+            members.iter().any(|m| m.role == OrgRole::Admin),
+            ErrorCode::InvalidOrgMembers
+        );
+        Organization::validate_members(&members, threshold)?;
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.organization = organization.key();
+        proposal.nonce = organization.update_nonce;
+        proposal.members = members;
+        proposal.threshold = threshold;
+        proposal.approvers = vec![admin];
+        proposal.executed = false;
+        proposal.bump = ctx.bumps.proposal;
+
+        organization.update_nonce += 1;
+
+        emit!(OrgUpdateSignedEvent {
+            organization: organization.key(),
+            nonce: proposal.nonce,
+            approver: admin,
+            approvals: 1,
+            threshold: organization.threshold,
+        });
+
+        msg!("Organization update {} proposed", proposal.nonce);
+        Ok(())
+    }
+
+    
+    pub fn sign_org_update(ctx: Context<SignOrgUpdate>) -> Result<()> {
+        let organization = &ctx.accounts.organization;
+        let proposal = &mut ctx.accounts.proposal;
+        let member = ctx.accounts.member.key();
+        require!(organization.can_approve(&member), ErrorCode::UnauthorizedOrgMember);
+        require!(!proposal.executed, ErrorCode::OrgApprovalExecuted);
+        require!(!proposal.approvers.contains(&member), ErrorCode::AlreadyVoted);
+
+        proposal.approvers.push(member);
+
+        emit!(OrgUpdateSignedEvent {
+            organization: organization.key(),
+            nonce: proposal.nonce,
+            approver: member,
+            approvals: proposal.approvers.len() as u8,
+            threshold: organization.threshold,
+        });
+
+        msg!("Organization update signed by: {}", member);
+        Ok(())
+    }
+
+    
+    pub fn execute_org_update(ctx: Context<ExecuteOrgUpdate>) -> Result<()> {
+        let organization = &mut ctx.accounts.organization;
+        let proposal = &mut ctx.accounts.proposal;
+        require!(!proposal.executed, ErrorCode::OrgApprovalExecuted);
+        require!(
+            proposal.nonce + 1 == organization.update_nonce,
+            ErrorCode::StaleOrgUpdate
+        );
+        require!(
+            organization.threshold_met(&proposal.approvers),
+            ErrorCode::OrgThresholdNotMet
+        );
+
+        organization.members = proposal.members.clone();
+        organization.threshold = proposal.threshold;
+        proposal.executed = true;
+
+        msg!("Organization updated: {}", organization.org_id);
+        Ok(())
+    }
+
+    
+    pub fn assign_contract_to_organization(ctx: Context<AssignContractToOrganization>) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        let organization = &ctx.accounts.organization;
+        require!(ctx.accounts.client.key() == contract.client, ErrorCode::UnauthorizedClient);
+        require!(contract.status == ContractStatus::Active, ErrorCode::InvalidContractStatus);
+        require!(
+            organization.role_of(&ctx.accounts.client.key()) == Some(OrgRole::Admin),
+            ErrorCode::UnauthorizedOrgMember
+        );
+
+        contract.organization = Some(organization.key());
+
+        msg!("Contract {} assigned to organization {}", contract.contract_id, organization.org_id);
+        Ok(())
+    }
+
+    
+    pub fn propose_org_approval(
+        ctx: Context<ProposeOrgApproval>,
+        action_index: u8,
+    ) -> Result<()> {
+        let contract = &ctx.accounts.contract;
+        let organization = &ctx.accounts.organization;
+        let member = ctx.accounts.member.key();
+        require!(contract.organization == Some(organization.key()), ErrorCode::UnauthorizedOrgMember);
+        require!(organization.can_approve(&member), ErrorCode::UnauthorizedOrgMember);
+        require!(
+            action_index == FUNDING_ACTION_INDEX || (action_index as usize) < contract.milestones.len(),
+            ErrorCode::InvalidMilestoneIndex
+        );
+
+        let approval = &mut ctx.accounts.approval;
+        approval.contract = contract.key();
+        approval.organization = organization.key();
+        approval.action_index = action_index;
+        approval.approvers = vec![member];
+        approval.executed = false;
+        approval.bump = ctx.bumps.approval;
+
+        emit!(OrgApprovalSignedEvent {
+            contract_id: contract.contract_id.clone(),
+            action_index,
+            approver: member,
+            approvals: 1,
+            threshold: organization.threshold,
+        });
+
+        msg!("Organization approval proposed for action {}", action_index);
+        Ok(())
+    }
+
+    
+    pub fn sign_org_approval(ctx: Context<SignOrgApproval>) -> Result<()> {
+        let organization = &ctx.accounts.organization;
+        let approval = &mut ctx.accounts.approval;
+        let member = ctx.accounts.member.key();
+        require!(organization.can_approve(&member), ErrorCode::UnauthorizedOrgMember);
+        require!(!approval.executed, ErrorCode::OrgApprovalExecuted);
+        require!(!approval.approvers.contains(&member), ErrorCode::AlreadyVoted);
+
+        approval.approvers.push(member);
+
+        emit!(OrgApprovalSignedEvent {
+            contract_id: ctx.accounts.contract.contract_id.clone(),
+            action_index: approval.action_index,
+            approver: member,
+            approvals: approval.approvers.len() as u8,
+            threshold: organization.threshold,
+        });
+
+        msg!("Organization approval signed by: {}", member);
+        Ok(())
+    }
+
+    
+    pub fn execute_org_deposit(ctx: Context<ExecuteOrgDeposit>) -> Result<()> {
+        let organization = &ctx.accounts.organization;
+        let approval = &mut ctx.accounts.approval;
+        require!(approval.action_index == FUNDING_ACTION_INDEX, ErrorCode::InvalidOrgApproval);
+        require!(!approval.executed, ErrorCode::OrgApprovalExecuted);
+        require!(
+            organization.threshold_met(&approval.approvers),
+            ErrorCode::OrgThresholdNotMet
+        );
+
+        let contract = &mut ctx.accounts.contract;
+        require!(contract.contract_type == ContractType::FixedPrice, ErrorCode::InvalidContractType);
+        require!(contract.status == ContractStatus::Active, ErrorCode::InvalidContractStatus);
+        require!(contract.nda_satisfied(), ErrorCode::NdaNotSigned);
+
+        let amount = contract.total_amount;
+        let org_id = organization.org_id.clone();
+        let org_bump = organization.bump;
+
+        let seeds = &[
+            b"organization",
+            org_id.as_bytes(),
+            &[org_bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.treasury_token_account.to_account_info(),
+            to: ctx.accounts.escrow_token_account.to_account_info(),
+            authority: organization.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, amount)?;
+
+        contract.status = ContractStatus::Funded;
+        approval.executed = true;
+
+        msg!("Escrow funded from organization treasury: {} tokens", amount);
+        Ok(())
+    }
+
+    
+    pub fn execute_org_milestone_approval<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteOrgMilestoneApproval<'info>>,
+    ) -> Result<()> {
+        let organization = &ctx.accounts.organization;
+        let approval = &mut ctx.accounts.approval;
+        require!(approval.action_index != FUNDING_ACTION_INDEX, ErrorCode::InvalidOrgApproval);
+        require!(!approval.executed, ErrorCode::OrgApprovalExecuted);
+        require!(
+            organization.threshold_met(&approval.approvers),
+            ErrorCode::OrgThresholdNotMet
+        );
+
+        approval.executed = true;
+        let milestone_index = approval.action_index;
+
+        process_milestone_approval(
+            &mut ctx.accounts.contract,
+            milestone_index,
+            organization.key(),
+            &ctx.accounts.escrow_token_account,
+            ctx.accounts.freelancer_token_account.as_ref(),
+            &ctx.accounts.token_program,
+            ctx.remaining_accounts,
+        )
+    }
+
+    
+    pub fn open_dispute(
+        ctx: Context<OpenDispute>,
+        category: DisputeCategory,
+        reason: String,
+        description: String,
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        let dispute = &mut ctx.accounts.dispute;
+
+        require!(
+            ctx.accounts.initiator.key() == contract.client ||
+            ctx.accounts.initiator.key() == contract.freelancer,
+            ErrorCode::UnauthorizedDispute
+        );
+
+        initialize_dispute(
+            contract,
+            dispute,
+            &ctx.accounts.config,
+            ctx.accounts.initiator.key(),
+            category,
+            reason,
+            description,
+        )?;
+        ctx.accounts.dispute.bump = ctx.bumps.dispute;
+
+        msg!("Dispute opened");
+        Ok(())
+    }
+
+    
+    pub fn open_key_dispute(
+        ctx: Context<OpenDispute>,
+        milestone_index: u8,
+    ) -> Result<()> {
+        let contract = &mut ctx.accounts.contract;
+        require!(ctx.accounts.initiator.key() == contract.client, ErrorCode::UnauthorizedClient);
+        require!((milestone_index as usize) < contract.milestones.len(), ErrorCode::InvalidMilestoneIndex);
+
+        let milestone = &contract.milestones[milestone_index as usize];
+        let key_mismatch = milestone.status == MilestoneStatus::KeyMismatch;
+        require!(
+            milestone.status == MilestoneStatus::AwaitingKey || key_mismatch,
+            ErrorCode::InvalidMilestoneStatus
+        );
+        require!(
+            key_mismatch || Clock::get()?.unix_timestamp > milestone.key_reveal_deadline,
+            ErrorCode::KeyRevealWindowOpen
+        );
+        let reason = if key_mismatch {
+            format!("Revealed key does not match commitment for milestone {}", milestone_index)
+        } else {
+            format!("Decryption key not revealed for milestone {}", milestone_index)
+        };
+
+        initialize_dispute(
+            contract,
+            &mut ctx.accounts.dispute,
+            &ctx.accounts.config,
+            ctx.accounts.initiator.key(),
+            DisputeCategory::Quality,
+            reason,
+            String::new(),
+        )?;
+        ctx.accounts.dispute.bump = ctx.bumps.dispute;
+
+        msg!("Dispute opened for unrevealed key on milestone {}", milestone_index);
+        Ok(())
+    }
+
+    
+    pub fn submit_arbitrator_vote(
+        ctx: Context<SubmitArbitratorVote>,
+        vote_for_client: bool,
+        reasoning: String,
+    ) -> Result<()> {
+        let dispute = &mut ctx.accounts.dispute;
+        require!(dispute.status == DisputeStatus::UnderReview, ErrorCode::InvalidDisputeStatus);
+
+        
+        require!(
+            dispute.arbitrators.contains(&ctx.accounts.arbitrator.key()),
+            ErrorCode::UnauthorizedArbitrator
+        );
+
+        
+        require!(
+            !dispute.votes.iter().any(|v| v.arbitrator == ctx.accounts.arbitrator.key()),
+            ErrorCode::AlreadyVoted
+        );
+
+        dispute.votes.push(ArbitratorVote {
+            arbitrator: ctx.accounts.arbitrator.key(),
+            vote_for_client,
+            reasoning,
+            voted_at: Clock::get()?.unix_timestamp,
+        });
+
+        
+        if dispute.votes.len() >= 2 {
+            let client_votes = dispute.votes.iter().filter(|v| v.vote_for_client).count();
+            dispute.status = if client_votes >= 2 {
+                DisputeStatus::ResolvedForClient
+            } else {
+                DisputeStatus::ResolvedForFreelancer
+            };
+            dispute.resolved_at = Clock::get()?.unix_timestamp;
+
+            emit!(DisputeResolvedEvent {
+                dispute: dispute.key(),
+                status: dispute.status.clone(),
+                default_judgment: false,
+            });
+        }
+
+        msg!("Arbitrator vote submitted");
+        Ok(())
+    }
+
+    
+    pub fn initialize_arbitration_config(
+        ctx: Context<InitializeArbitrationConfig>,
+        evidence_period: i64,
+        voting_period: i64,
+        max_reassignments: u8,
+        arbitrator_fee: u64,
+        arbitrator_pool: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(evidence_period > 0 && voting_period > 0, ErrorCode::InvalidDisputePeriod);
+        require!(
+            arbitrator_pool.len() >= ARBITRATORS_PER_DISPUTE && arbitrator_pool.len() <= 20,
+            ErrorCode::InvalidArbitratorPool
+        );
+
+        let config = &mut ctx.accounts.config;
+        config.admin = ctx.accounts.admin.key();
+        config.evidence_period = evidence_period;
+        config.voting_period = voting_period;
+        config.max_reassignments = max_reassignments;
+        config.arbitrator_fee = arbitrator_fee;
+        config.arbitrator_pool = arbitrator_pool;
+        config.bump = ctx.bumps.config;
+
+        msg!("Arbitration config initialized");
+        Ok(())
+    }
+
+    
+    pub fn update_arbitration_config(
+        ctx: Context<UpdateArbitrationConfig>,
+        evidence_period: i64,
+        voting_period: i64,
+        max_reassignments: u8,
+        arbitrator_fee: u64,
+        arbitrator_pool: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(evidence_period > 0 && voting_period > 0, ErrorCode::InvalidDisputePeriod);
+        require!(
+            arbitrator_pool.len() >= ARBITRATORS_PER_DISPUTE && arbitrator_pool.len() <= 20,
+            ErrorCode::InvalidArbitratorPool
+        );
+
+        let config = &mut ctx.accounts.config;
+        config.evidence_period = evidence_period;
+        config.voting_period = voting_period;
+        config.max_reassignments = max_reassignments;
+        config.arbitrator_fee = arbitrator_fee;
+        config.arbitrator_pool = arbitrator_pool;
+
+        msg!("Arbitration config updated");
+        Ok(())
+    }
+
+    
+    pub fn submit_dispute_evidence(
+        ctx: Context<SubmitDisputeEvidence>,
+        ipfs_hash: String,
+    ) -> Result<()> {
+        let dispute = &mut ctx.accounts.dispute;
+        let party = ctx.accounts.party.key();
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(dispute.status == DisputeStatus::Open, ErrorCode::InvalidDisputeStatus);
+        require!(current_time <= dispute.phase_deadline, ErrorCode::DisputePhaseExpired);
+        require!(
+            party == dispute.initiator || party == dispute.respondent,
+            ErrorCode::UnauthorizedDispute
+        );
+        require!(
+            !dispute.evidence.iter().any(|e| e.party == party),
+            ErrorCode::EvidenceAlreadySubmitted
+        );
+
+        dispute.evidence.push(DisputeEvidence {
+            party,
+            ipfs_hash,
+            submitted_at: current_time,
+        });
+
+        if dispute.evidence.len() == 2 {
+            let seed = Clock::get()?.slot;
+            let arbitrators = ctx.accounts.config.draw_arbitrators(
+                &[dispute.initiator, dispute.respondent],
+                ARBITRATORS_PER_DISPUTE,
+                seed,
+            );
+            dispute.arbitrators = arbitrators;
+            dispute.status = DisputeStatus::UnderReview;
+            dispute.phase_deadline = current_time + ctx.accounts.config.voting_period;
+
+            emit!(ArbitratorsAssignedEvent {
+                dispute: dispute.key(),
+                arbitrators: dispute.arbitrators.clone(),
+                voting_deadline: dispute.phase_deadline,
+            });
+        }
+
+        msg!("Dispute evidence submitted by: {}", party);
+        Ok(())
+    }
+
+    
+    pub fn advance_dispute(ctx: Context<AdvanceDispute>) -> Result<()> {
+        let contract = &ctx.accounts.contract;
+        let config = &ctx.accounts.config;
+        let dispute = &mut ctx.accounts.dispute;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(current_time > dispute.phase_deadline, ErrorCode::DisputePhaseNotExpired);
+
+        match dispute.status {
+            DisputeStatus::Open => {
+                let initiator_submitted = dispute.evidence.iter().any(|e| e.party == dispute.initiator);
+                let respondent_submitted = dispute.evidence.iter().any(|e| e.party == dispute.respondent);
+
+                let winner = match (initiator_submitted, respondent_submitted) {
+                    (true, false) => Some(dispute.initiator),
+                    (false, true) => Some(dispute.respondent),
+                    _ => None,
+                };
+
+                dispute.status = match winner {
+                    Some(party) if party == contract.client => DisputeStatus::ResolvedForClient,
+                    Some(_) => DisputeStatus::ResolvedForFreelancer,
+                    None => DisputeStatus::Cancelled,
+                };
+                dispute.resolved_at = current_time;
+
+                emit!(DisputeResolvedEvent {
+                    dispute: dispute.key(),
+                    status: dispute.status.clone(),
+                    default_judgment: winner.is_some(),
+                });
+            }
+            DisputeStatus::UnderReview => {
+                if !dispute.votes.is_empty() {
+                    let client_votes = dispute.votes.iter().filter(|v| v.vote_for_client).count();
+                    let freelancer_votes = dispute.votes.len() - client_votes;
+                    dispute.status = if client_votes > freelancer_votes {
+                        DisputeStatus::ResolvedForClient
+                    } else {
+                        DisputeStatus::ResolvedForFreelancer
+                    };
+                    dispute.resolved_at = current_time;
+
+                    emit!(DisputeResolvedEvent {
+                        dispute: dispute.key(),
+                        status: dispute.status.clone(),
+                        default_judgment: false,
+                    });
+                } else {
+                    let mut excluded = dispute.arbitrators.clone();
+                    excluded.push(dispute.initiator);
+                    excluded.push(dispute.respondent);
+
+                    let seed = Clock::get()?.slot;
+                    let arbitrators = config.draw_arbitrators(&excluded, ARBITRATORS_PER_DISPUTE, seed);
+
+                    if arbitrators.is_empty() || dispute.reassignment_count >= config.max_reassignments {
+                        dispute.status = DisputeStatus::Cancelled;
+                        dispute.resolved_at = current_time;
+
+                        emit!(DisputeResolvedEvent {
+                            dispute: dispute.key(),
+                            status: dispute.status.clone(),
+                            default_judgment: false,
+                        });
+                    } else {
+                        dispute.arbitrators = arbitrators;
+                        dispute.reassignment_count += 1;
+                        dispute.phase_deadline = current_time + config.voting_period;
+
+                        emit!(ArbitratorsAssignedEvent {
+                            dispute: dispute.key(),
+                            arbitrators: dispute.arbitrators.clone(),
+                            voting_deadline: dispute.phase_deadline,
+                        });
+                    }
+                }
+            }
+            _ => return Err(ErrorCode::InvalidDisputeStatus.into()),
+        }
+
+        msg!("Dispute advanced by: {}", ctx.accounts.cranker.key());
+        Ok(())
+    }
+
+    
+    pub fn execute_dispute_ruling<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteDisputeRuling<'info>>,
+    ) -> Result<()> {
+        let dispute = &ctx.accounts.dispute;
+        let contract = &ctx.accounts.contract;
+        require!(contract.status == ContractStatus::Disputed, ErrorCode::InvalidContractStatus);
+        require!(
+            dispute.status == DisputeStatus::ResolvedForClient ||
+            dispute.status == DisputeStatus::ResolvedForFreelancer ||
+            dispute.status == DisputeStatus::Cancelled,
+            ErrorCode::InvalidDisputeStatus
+        );
+
+        let contract_id = contract.contract_id.clone();
+
+        if dispute.status == DisputeStatus::Cancelled {
+            let contract = &mut ctx.accounts.contract;
+            contract.status = dispute.contract_status.clone();
+
+            emit!(DisputeRulingExecutedEvent {
+                contract_id,
+                dispute: dispute.key(),
+                status: dispute.status.clone(),
+                client_amount: 0,
+                freelancer_amount: 0,
+                arbitrator_fees: 0,
+            });
+
+            msg!("Dispute cancelled, contract status restored");
+            return Ok(());
+        }
+
+        require!(
+            ctx.remaining_accounts.len() >= dispute.votes.len(),
+            ErrorCode::InvalidArbitratorTokenAccount
+        );
+        let (arbitrator_accounts, contributor_accounts) = ctx.remaining_accounts.split_at(dispute.votes.len());
+
+        let contract_bump = contract.bump;
+        let remaining = contract.escrowed_amount(&dispute.contract_status);
+
+        let fees_paid = pay_arbitrator_fees(
+            contract,
+            &dispute.votes,
+            ctx.accounts.config.arbitrator_fee,
+            remaining,
+            &ctx.accounts.escrow_token_account,
+            &ctx.accounts.token_program,
+            arbitrator_accounts,
+        )?;
+
+        let seeds = &[
+            b"contract",
+            contract_id.as_bytes(),
+            &[contract_bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let distributable = remaining - fees_paid;
+        let for_client = dispute.status == DisputeStatus::ResolvedForClient;
+        let unclaimed_bonus = contract
+            .milestones
+            .iter()
+            .try_fold(0u64, |acc, m| acc.checked_add(m.conditional_bonus))
+            .ok_or(ErrorCode::Overflow)?;
+        let (client_amount, freelancer_amount, freelancer_bonus) = if for_client {
+            (distributable + unclaimed_bonus, 0, 0)
+        } else {
+            (0, distributable, unclaimed_bonus)
+        };
+
+        if client_amount > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.client_token_account.to_account_info(),
+                authority: contract.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            token::transfer(cpi_ctx, client_amount)?;
+        }
+
+        pay_freelancer_share(
+            contract,
+            &contract.contributors,
+            freelancer_amount + freelancer_bonus,
+            &ctx.accounts.escrow_token_account,
+            ctx.accounts.freelancer_token_account.as_ref(),
+            &ctx.accounts.token_program,
+            contributor_accounts,
+        )?;
+
+        let contract = &mut ctx.accounts.contract;
+        contract.paid_amount += freelancer_amount;
+        contract.bonus_paid_amount += freelancer_bonus;
+        contract.status = if for_client {
+            ContractStatus::Cancelled
+        } else {
+            ContractStatus::Completed
+        };
+        for milestone in contract.milestones.iter_mut() {
+            milestone.conditional_bonus = 0;
+        }
+
+        emit!(DisputeRulingExecutedEvent {
+            contract_id,
+            dispute: dispute.key(),
+            status: dispute.status.clone(),
+            client_amount,
+            freelancer_amount,
+            arbitrator_fees: fees_paid,
+        });
+
+        msg!("Dispute ruling executed: {} to client, {} to freelancer", client_amount, freelancer_amount);
+        Ok(())
+    }
+
+    
+
+    
+    pub fn propose_settlement(
+        ctx: Context<ProposeSettlement>,
+        client_share_bps: u16,
+    ) -> Result<()> {
+        let dispute = &mut ctx.accounts.dispute;
+        let proposer = ctx.accounts.proposer.key();
+
+        require!(
+            dispute.status == DisputeStatus::Open || dispute.status == DisputeStatus::UnderReview,
+            ErrorCode::InvalidDisputeStatus
+        );
+        require!(
+            proposer == dispute.initiator || proposer == dispute.respondent,
+            ErrorCode::UnauthorizedDispute
+        );
+        require!(client_share_bps <= 10_000, ErrorCode::InvalidSettlementSplit);
+
+        dispute.settlement = Some(SettlementOffer {
+            proposer,
+            client_share_bps,
+            proposed_at: Clock::get()?.unix_timestamp,
+        });
+
+        emit!(SettlementProposedEvent {
+            dispute: dispute.key(),
+            proposer,
+            client_share_bps,
+        });
+
+        msg!("Settlement proposed: {} bps to client", client_share_bps);
+        Ok(())
+    }
+
+    
+    pub fn accept_settlement<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptSettlement<'info>>,
+        expected_client_share_bps: u16,
+    ) -> Result<()> {
+        let acceptor = ctx.accounts.acceptor.key();
+        let dispute = &ctx.accounts.dispute;
+
+        require!(
+            dispute.status == DisputeStatus::Open || dispute.status == DisputeStatus::UnderReview,
+            ErrorCode::InvalidDisputeStatus
+        );
+        let offer = dispute.settlement.clone().ok_or(ErrorCode::NoSettlementOffer)?;
+        require!(
+            (acceptor == dispute.initiator || acceptor == dispute.respondent) && acceptor != offer.proposer,
+            ErrorCode::UnauthorizedDispute
+        );
+        require!(
+            offer.client_share_bps == expected_client_share_bps,
+            ErrorCode::SettlementOfferChanged
+        );
+        require!(
+            ctx.remaining_accounts.len() >= dispute.votes.len(),
+            ErrorCode::InvalidArbitratorTokenAccount
+        );
+        let (arbitrator_accounts, contributor_accounts) = ctx.remaining_accounts.split_at(dispute.votes.len());
+
+        let contract = &ctx.accounts.contract;
+        let contract_id = contract.contract_id.clone();
+        let contract_bump = contract.bump;
+        let remaining = contract.escrowed_amount(&dispute.contract_status);
+
+        let fees_paid = pay_arbitrator_fees(
+            contract,
+            &dispute.votes,
+            ctx.accounts.config.arbitrator_fee,
+            remaining,
+            &ctx.accounts.escrow_token_account,
+            &ctx.accounts.token_program,
+            arbitrator_accounts,
+        )?;
+
+        let seeds = &[
+            b"contract",
+            contract_id.as_bytes(),
+            &[contract_bump],
+        ];
+        let signer = &[&seeds[..]];
+
+        let distributable = remaining - fees_paid;
+        let client_amount = (distributable as u128 * offer.client_share_bps as u128 / 10_000) as u64;
+        let freelancer_amount = distributable - client_amount;
+        let unclaimed_bonus = contract
+            .milestones
+            .iter()
+            .try_fold(0u64, |acc, m| acc.checked_add(m.conditional_bonus))
+            .ok_or(ErrorCode::Overflow)?;
+        let client_bonus = (unclaimed_bonus as u128 * offer.client_share_bps as u128 / 10_000) as u64;
+        let freelancer_bonus = unclaimed_bonus - client_bonus;
+
+        if client_amount + client_bonus > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.escrow_token_account.to_account_info(),
+                to: ctx.accounts.client_token_account.to_account_info(),
+                authority: contract.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            token::transfer(cpi_ctx, client_amount + client_bonus)?;
+        }
+
+        pay_freelancer_share(
+            contract,
+            &contract.contributors,
+            freelancer_amount + freelancer_bonus,
+            &ctx.accounts.escrow_token_account,
+            ctx.accounts.freelancer_token_account.as_ref(),
+            &ctx.accounts.token_program,
+            contributor_accounts,
+        )?;
+
+        let contract = &mut ctx.accounts.contract;
+        contract.paid_amount += freelancer_amount;
+        contract.bonus_paid_amount += freelancer_bonus;
+        contract.status = if client_amount + client_bonus == 0 {
+            ContractStatus::Completed
+        } else {
+            ContractStatus::Cancelled
+        };
+        for milestone in contract.milestones.iter_mut() {
+            milestone.conditional_bonus = 0;
+        }
+
+        let dispute = &mut ctx.accounts.dispute;
+        dispute.status = DisputeStatus::Settled;
+        dispute.resolved_at = Clock::get()?.unix_timestamp;
+
+        emit!(SettlementAcceptedEvent {
+            contract_id,
+            dispute: dispute.key(),
+            client_amount,
+            freelancer_amount,
+            arbitrator_fees: fees_paid,
+        });
+
+        msg!("Settlement accepted: {} to client, {} to freelancer", client_amount, freelancer_amount);
+        Ok(())
+    }
+
+    
+    pub fn initialize_reputation(ctx: Context<InitializeReputation>, user: Pubkey) -> Result<()> {
+        let reputation = &mut ctx.accounts.reputation;
+        reputation.user = user;
+        reputation.review_count = 0;
+        reputation.total_score = 0;
+        reputation.average_score = 0;
+        reputation.last_review_at = 0;
+        reputation.bump = ctx.bumps.reputation;
+
+        msg!("Reputation initialized for: {}", user);
+        Ok(())
+    }
+
+    
+    pub fn submit_review(
+        ctx: Context<SubmitReview>,
+        score: u8,
+        comment_hash: [u8; 32],
+    ) -> Result<()> {
+        let contract = &ctx.accounts.contract;
+        let reviewer = ctx.accounts.reviewer.key();
+        require!(contract.status == ContractStatus::Completed, ErrorCode::InvalidContractStatus);
+        require!(score >= 1 && score <= 5, ErrorCode::InvalidReviewScore);
+
+        let reviewee = if reviewer == contract.client {
+            contract.freelancer
+        } else if reviewer == contract.freelancer {
+            contract.client
+        } else {
+            return Err(ErrorCode::UnauthorizedSigner.into());
+        };
+        require!(ctx.accounts.reputation.user == reviewee, ErrorCode::InvalidReputationAccount);
+
+        let current_time = Clock::get()?.unix_timestamp;
+
+        let review = &mut ctx.accounts.review;
+        review.contract = contract.key();
+        review.reviewer = reviewer;
+        review.reviewee = reviewee;
+        review.score = score;
+        review.comment_hash = comment_hash;
+        review.created_at = current_time;
+        review.bump = ctx.bumps.review;
+
+        let reputation = &mut ctx.accounts.reputation;
+        reputation.review_count += 1;
+        reputation.total_score += score as u64;
+        reputation.average_score = (reputation.total_score * 100 / reputation.review_count as u64) as u16;
+        reputation.last_review_at = current_time;
+
+        emit!(ReviewSubmittedEvent {
+            contract_id: contract.contract_id.clone(),
+            reviewer,
+            reviewee,
+            score,
+            average_score: reputation.average_score,
+        });
+
+        msg!("Review submitted for: {}", reviewee);
+        Ok(())
+    }
+
+    
+    pub fn start_time_session(
+        ctx: Context<StartTimeSession>,
+        _contract_id: String,
+        milestone_index: u8,
+        _session_nonce: u64,
+    ) -> Result<()> {
+        require!(ctx.accounts.contract.nda_satisfied(), ErrorCode::NdaNotSigned);
+
+        ctx.accounts.contract.time_session_count += 1;
+
+        let session = &mut ctx.accounts.session;
+        session.contract = ctx.accounts.contract.key();
+        session.freelancer = ctx.accounts.freelancer.key();
+        session.milestone_index = milestone_index;
+        session.start_time = Clock::get()?.unix_timestamp;
+        session.end_time = 0;
+        session.duration = 0;
+        session.description = String::new();
+        session.bump = ctx.bumps.session;
+
+        msg!("Time session started");
+        Ok(())
+    }
+
+    
+    pub fn end_time_session(
+        ctx: Context<EndTimeSession>,
+        description: String,
+    ) -> Result<()> {
+        let session = &mut ctx.accounts.session;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        session.end_time = current_time;
+        session.duration = current_time - session.start_time;
+        session.description = description;
+
+        msg!("Time session ended: {} seconds", session.duration);
+        Ok(())
+    }
+}
+
+fn process_milestone_approval<'info>(
+    contract: &mut Account<'info, Contract>,
+    milestone_index: u8,
+    approved_by: Pubkey,
+    escrow_token_account: &Account<'info, TokenAccount>,
+    freelancer_token_account: Option<&Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    require!(contract.status == ContractStatus::Funded, ErrorCode::InvalidContractStatus);
+    require!((milestone_index as usize) < contract.milestones.len(), ErrorCode::InvalidMilestoneIndex);
+
+    require!(
+        contract.milestones[milestone_index as usize].status == MilestoneStatus::UnderReview,
+        ErrorCode::InvalidMilestoneStatus
+    );
+    require!(contract.prerequisites_met(milestone_index), ErrorCode::MilestonePrerequisitesIncomplete);
+    require!(
+        contract.milestones[milestone_index as usize].open_subcontracts == 0,
+        ErrorCode::SubcontractsOutstanding
+    );
+
+    contract.milestones[milestone_index as usize].approved_by = approved_by;
+
+    if contract.milestones[milestone_index as usize].encrypted {
+        let reveal_deadline = Clock::get()?.unix_timestamp + KEY_REVEAL_WINDOW;
+
+        let milestone = &mut contract.milestones[milestone_index as usize];
+        milestone.status = MilestoneStatus::AwaitingKey;
+        milestone.key_reveal_deadline = reveal_deadline;
+
+        emit!(KeyRevealRequestedEvent {
+            contract_id: contract.contract_id.clone(),
+            milestone_index,
+            reveal_deadline,
+        });
+
+        msg!("Milestone {} approved, awaiting decryption key", milestone_index);
+        return Ok(());
+    }
+
+    release_milestone_payment(
+        contract,
+        milestone_index,
+        escrow_token_account,
+        freelancer_token_account,
+        token_program,
+        remaining_accounts,
+    )
+}
+
+fn release_milestone_payment<'info>(
+    contract: &mut Account<'info, Contract>,
+    milestone_index: u8,
+    escrow_token_account: &Account<'info, TokenAccount>,
+    freelancer_token_account: Option<&Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let milestone = &contract.milestones[milestone_index as usize];
+    let amount = milestone.amount - milestone.subcontracted_amount;
+    let deadline_met = milestone
+        .deliverables
+        .first()
+        .map_or(false, |d| d.uploaded_at <= milestone.deadline);
+    let bonus = if deadline_met { milestone.conditional_bonus } else { 0 };
+
+    let contract_id = contract.contract_id.clone();
+
+    let shares = contract.payout_shares(milestone_index).to_vec();
+    let payouts = pay_freelancer_share(
+        contract,
+        &shares,
+        amount + bonus,
+        escrow_token_account,
+        freelancer_token_account,
+        token_program,
+        remaining_accounts,
+    )?;
+    for (share, payout) in shares.iter().zip(payouts.iter()) {
+        emit!(ContributorPaidEvent {
+            contract_id: contract_id.clone(),
+            milestone_index,
+            contributor: share.wallet,
+            amount: *payout,
+        });
+    }
+
+    contract.milestones[milestone_index as usize].status = MilestoneStatus::Completed;
+    contract.milestones[milestone_index as usize].completed_at = Clock::get()?.unix_timestamp;
+    contract.paid_amount += contract.milestones[milestone_index as usize].amount;
+
+    if bonus > 0 {
+        contract.milestones[milestone_index as usize].conditional_bonus = 0;
+        contract.milestones[milestone_index as usize].bonus_paid += bonus;
+        contract.bonus_paid_amount += bonus;
+
+        emit!(BonusPaidEvent {
+            contract_id: contract_id.clone(),
+            milestone_index,
+            freelancer: contract.freelancer,
+            amount: bonus,
+            conditional: true,
+        });
+    }
+
+    if contract.milestones.iter().all(|m| m.status == MilestoneStatus::Completed) {
+        contract.status = ContractStatus::Completed;
+    }
+
+    emit!(MilestoneApprovedEvent {
+        contract_id,
+        milestone_index,
+        amount,
+        approved_by: contract.milestones[milestone_index as usize].approved_by,
+    });
+
+    msg!("Milestone {} approved, {} tokens released", milestone_index, amount);
+    Ok(())
+}
+
+fn initialize_dispute(
+    contract: &mut Account<Contract>,
+    dispute: &mut Account<Dispute>,
+    config: &ArbitrationConfig,
+    initiator: Pubkey,
+    category: DisputeCategory,
+    reason: String,
+    description: String,
+) -> Result<()> {
+    require!(
+        matches!(
+            contract.status,
+            ContractStatus::Active | ContractStatus::Funded | ContractStatus::InProgress
+        ),
+        ErrorCode::InvalidContractStatus
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    dispute.contract = contract.key();
+    dispute.initiator = initiator;
+    dispute.respondent = if initiator == contract.client {
+        contract.freelancer
+    } else {
+        contract.client
+    };
+    dispute.category = category;
+    dispute.reason = reason;
+    dispute.description = description;
+    dispute.status = DisputeStatus::Open;
+    dispute.created_at = current_time;
+    dispute.phase_deadline = current_time + config.evidence_period;
+    dispute.evidence = vec![];
+    dispute.arbitrators = vec![];
+    dispute.votes = vec![];
+    dispute.reassignment_count = 0;
+    dispute.resolved_at = 0;
+    dispute.settlement = None;
+
+    dispute.contract_status = contract.status.clone();
+
+    contract.status = ContractStatus::Disputed;
+
+    emit!(DisputeOpenedEvent {
+        contract_id: contract.contract_id.clone(),
+        initiator,
+        category: dispute.category.clone(),
+    });
+
+    Ok(())
+}
+
+fn pay_freelancer_share<'info>(
+    contract: &Account<'info, Contract>,
+    shares: &[Contributor],
+    amount: u64,
+    escrow_token_account: &Account<'info, TokenAccount>,
+    freelancer_token_account: Option<&Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+    contributor_accounts: &[AccountInfo<'info>],
+) -> Result<Vec<u64>> {
+    let contract_id = contract.contract_id.clone();
+    let contract_bump = contract.bump;
+
+    let seeds = &[
+        b"contract",
+        contract_id.as_bytes(),
+        &[contract_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    distribute_share(
+        contract.freelancer,
+        shares,
+        amount,
+        escrow_token_account.to_account_info(),
+        contract.to_account_info(),
+        signer,
+        freelancer_token_account,
+        token_program,
+        contributor_accounts,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn distribute_share<'info>(
+    freelancer: Pubkey,
+    shares: &[Contributor],
+    amount: u64,
+    from: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    signer: &[&[&[u8]]],
+    freelancer_token_account: Option<&Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+    contributor_accounts: &[AccountInfo<'info>],
+) -> Result<Vec<u64>> {
+    if shares.is_empty() {
+        let freelancer_token_account = freelancer_token_account.ok_or(ErrorCode::UnauthorizedFreelancer)?;
+        require!(
+            freelancer_token_account.owner == freelancer,
+            ErrorCode::UnauthorizedFreelancer
+        );
+
+        if amount > 0 {
+            let cpi_accounts = Transfer {
+                from,
+                to: freelancer_token_account.to_account_info(),
+                authority,
+            };
+            let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+            token::transfer(cpi_ctx, amount)?;
+        }
+        return Ok(vec![]);
+    }
+
+    require!(
+        contributor_accounts.len() == shares.len(),
+        ErrorCode::InvalidContributorTokenAccount
+    );
+
+    let payouts = Contributor::split(shares, amount);
+    for ((share, payout), contributor_account) in shares
+        .iter()
+        .zip(payouts.iter())
+        .zip(contributor_accounts.iter())
+    {
+        let contributor_token_account = Account::<TokenAccount>::try_from(contributor_account)?;
+        require!(
+            contributor_token_account.owner == share.wallet,
+            ErrorCode::InvalidContributorTokenAccount
+        );
+
+        if *payout == 0 {
+            continue;
+        }
+
+        let cpi_accounts = Transfer {
+            from: from.clone(),
+            to: contributor_account.clone(),
+            authority: authority.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, *payout)?;
+    }
+
+    Ok(payouts)
+}
+
+fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let shortfall = rent.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    account.realloc(space, true)?;
+    Ok(())
+}
+
+fn pay_arbitrator_fees<'info>(
+    contract: &Account<'info, Contract>,
+    votes: &[ArbitratorVote],
+    fee: u64,
+    available: u64,
+    escrow_token_account: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    arbitrator_accounts: &[AccountInfo<'info>],
+) -> Result<u64> {
+    let contract_id = contract.contract_id.clone();
+    let contract_bump = contract.bump;
+
+    let seeds = &[
+        b"contract",
+        contract_id.as_bytes(),
+        &[contract_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let mut fees_paid: u64 = 0;
+    for (vote, arbitrator_account) in votes.iter().zip(arbitrator_accounts.iter()) {
+        let arbitrator_token_account = Account::<TokenAccount>::try_from(arbitrator_account)?;
+        require!(
+            arbitrator_token_account.owner == vote.arbitrator,
+            ErrorCode::InvalidArbitratorTokenAccount
+        );
+
+        let amount = fee.min(available - fees_paid);
+        if amount == 0 {
+            continue;
+        }
+
+        let cpi_accounts = Transfer {
+            from: escrow_token_account.to_account_info(),
+            to: arbitrator_account.clone(),
+            authority: contract.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, amount)?;
+
+        fees_paid += amount;
+    }
+
+    Ok(fees_paid)
+}
+
+
+
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct CreateContract<'info> {
+    #[account(
+        init,
+        payer = client,
+        space = 8 + Contract::INIT_SPACE,
+        seeds = [b"contract", contract_id.as_bytes()],
+        bump
+    )]
+    pub contract: Account<'info, Contract>,
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    
+    pub freelancer: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(job_id: String)]
+pub struct LinkJob<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+
+    
+    #[account(
+        seeds = [b"job", job_id.as_bytes()],
+        bump,
+        seeds::program = JOB_BOARD_PROGRAM_ID,
+        owner = JOB_BOARD_PROGRAM_ID @ ErrorCode::InvalidJobAccount
+    )]
+    pub job: UncheckedAccount<'info>,
+    pub client: Signer<'info>,
+}
 
+#[derive(Accounts)]
+pub struct MigrateContract<'info> {
     
+    #[account(mut, owner = crate::ID)]
+    pub contract: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
 
+#[derive(Accounts)]
+pub struct MigrateDispute<'info> {
     
-    pub fn start_time_session(
-        ctx: Context<StartTimeSession>,
-        _contract_id: String,
-        milestone_index: u8,
-        _session_nonce: u64,
-    ) -> Result<()> {
-        let session = &mut ctx.accounts.session;
-        session.contract = ctx.accounts.contract.key();
-        session.freelancer = ctx.accounts.freelancer.key();
-        session.milestone_index = milestone_index;
-        session.start_time = Clock::get()?.unix_timestamp;
-        session.end_time = 0;
-        session.duration = 0;
-        session.description = String::new();
-        session.bump = ctx.bumps.session;
+    #[account(
+        mut,
+        seeds = [b"dispute", contract.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub dispute: UncheckedAccount<'info>,
+    pub contract: Account<'info, Contract>,
+    #[account(
+        seeds = [b"arbitration-config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ArbitrationConfig>,
+    #[account(
+        constraint = escrow_token_account.owner == contract.key() @ ErrorCode::InvalidEscrowAccount,
+        constraint = escrow_token_account.mint == contract.payment_token @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
 
-        msg!("Time session started");
-        Ok(())
-    }
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct CreateSubcontract<'info> {
+    #[account(mut)]
+    pub parent_contract: Account<'info, Contract>,
+    #[account(
+        init,
+        payer = client,
+        space = 8 + Contract::INIT_SPACE,
+        seeds = [b"contract", contract_id.as_bytes()],
+        bump
+    )]
+    pub contract: Account<'info, Contract>,
+    #[account(mut)]
+    pub client: Signer<'info>,
 
     
-    pub fn end_time_session(
-        ctx: Context<EndTimeSession>,
-        description: String,
-    ) -> Result<()> {
-        let session = &mut ctx.accounts.session;
-        let current_time = Clock::get()?.unix_timestamp;
+    pub freelancer: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = parent_escrow_token_account.owner == parent_contract.key() @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub parent_escrow_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = escrow_token_account.owner == contract.key() @ ErrorCode::InvalidEscrowAccount,
+        constraint = escrow_token_account.mint == parent_escrow_token_account.mint @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
 
-        session.end_time = current_time;
-        session.duration = current_time - session.start_time;
-        session.description = description;
+#[derive(Accounts)]
+pub struct SettleSubcontract<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    #[account(mut)]
+    pub parent_contract: Account<'info, Contract>,
+    pub signer: Signer<'info>,
+}
 
-        msg!("Time session ended: {} seconds", session.duration);
-        Ok(())
-    }
+#[derive(Accounts)]
+#[instruction(contract_id: String)]
+pub struct CreateRetainerContract<'info> {
+    #[account(
+        init,
+        payer = client,
+        space = 8 + Contract::INIT_SPACE,
+        seeds = [b"contract", contract_id.as_bytes()],
+        bump
+    )]
+    pub contract: Account<'info, Contract>,
+    #[account(
+        init,
+        payer = client,
+        space = 8 + Retainer::INIT_SPACE,
+        seeds = [b"retainer", contract.key().as_ref()],
+        bump
+    )]
+    pub retainer: Account<'info, Retainer>,
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    
+    pub freelancer: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundRetainerPeriod<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    #[account(
+        mut,
+        seeds = [b"retainer", contract.key().as_ref()],
+        bump = retainer.bump
+    )]
+    pub retainer: Account<'info, Retainer>,
+    #[account(mut)]
+    pub client: Signer<'info>,
+    #[account(mut)]
+    pub client_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRetainerPeriod<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    #[account(
+        mut,
+        seeds = [b"retainer", contract.key().as_ref()],
+        bump = retainer.bump
+    )]
+    pub retainer: Account<'info, Retainer>,
+    pub freelancer: Signer<'info>,
+    #[account(mut)]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub freelancer_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
 }
 
+#[derive(Accounts)]
+pub struct RetainerObjection<'info> {
+    pub contract: Account<'info, Contract>,
+    #[account(
+        mut,
+        seeds = [b"retainer", contract.key().as_ref()],
+        bump = retainer.bump
+    )]
+    pub retainer: Account<'info, Retainer>,
+    pub client: Signer<'info>,
+}
 
+#[derive(Accounts)]
+pub struct GiveRetainerNotice<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    #[account(
+        mut,
+        seeds = [b"retainer", contract.key().as_ref()],
+        bump = retainer.bump
+    )]
+    pub retainer: Account<'info, Retainer>,
+    pub signer: Signer<'info>,
+}
 
 #[derive(Accounts)]
 #[instruction(contract_id: String)]
-pub struct CreateContract<'info> {
+pub struct CreateStreamContract<'info> {
     #[account(
         init,
         payer = client,
-        space = 8 + 4000, 
+        space = 8 + Contract::INIT_SPACE,
         seeds = [b"contract", contract_id.as_bytes()],
         bump
     )]
     pub contract: Account<'info, Contract>,
+    #[account(
+        init,
+        payer = client,
+        space = 8 + Stream::INIT_SPACE,
+        seeds = [b"stream", contract.key().as_ref()],
+        bump
+    )]
+    pub stream: Account<'info, Stream>,
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    
+    pub freelancer: AccountInfo<'info>,
+    #[account(mut)]
+    pub client_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFromStream<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    #[account(
+        mut,
+        seeds = [b"stream", contract.key().as_ref()],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, Stream>,
+    pub freelancer: Signer<'info>,
+    #[account(mut)]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub freelancer_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct StopStream<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    #[account(
+        mut,
+        seeds = [b"stream", contract.key().as_ref()],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, Stream>,
+    pub client: Signer<'info>,
+    #[account(mut)]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = client_token_account.owner == contract.client @ ErrorCode::UnauthorizedClient
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct DepositEscrow<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    #[account(mut)]
+    pub client: Signer<'info>,
+    #[account(mut)]
+    pub client_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SignNDA<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureTeam<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureMilestoneOrdering<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitDeliverable<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    pub freelancer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RequestRevision<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    pub client: Signer<'info>,
+    pub organization: Option<Account<'info, Organization>>,
+}
+
+#[derive(Accounts)]
+pub struct ManageDelegate<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    pub client: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveMilestone<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    #[account(mut)]
+    pub client: Signer<'info>,
+    #[account(mut)]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub freelancer_token_account: Option<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct EscrowConditionalBonus<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    pub client: Signer<'info>,
+    #[account(mut)]
+    pub client_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = escrow_token_account.owner == contract.key() @ ErrorCode::InvalidEscrowAccount,
+        constraint = escrow_token_account.mint == contract.payment_token @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimConditionalBonus<'info> {
     #[account(mut)]
+    pub contract: Account<'info, Contract>,
     pub client: Signer<'info>,
-
-    
-    pub freelancer: AccountInfo<'info>,
-    pub system_program: Program<'info, System>,
+    #[account(mut)]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = client_token_account.owner == contract.client @ ErrorCode::UnauthorizedClient
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct DepositEscrow<'info> {
+pub struct PayMilestoneBonus<'info> {
     #[account(mut)]
     pub contract: Account<'info, Contract>,
-    #[account(mut)]
     pub client: Signer<'info>,
     #[account(mut)]
     pub client_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = freelancer_token_account.owner == contract.freelancer @ ErrorCode::UnauthorizedFreelancer
+    )]
+    pub freelancer_token_account: Option<Account<'info, TokenAccount>>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RevealDeliverableKey<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    pub freelancer: Signer<'info>,
     #[account(mut)]
     pub escrow_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub freelancer_token_account: Option<Account<'info, TokenAccount>>,
     pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct SignNDA<'info> {
+#[instruction(org_id: String)]
+pub struct CreateOrganization<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Organization::INIT_SPACE,
+        seeds = [b"organization", org_id.as_bytes()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
     #[account(mut)]
-    pub contract: Account<'info, Contract>,
-    pub signer: Signer<'info>,
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct SubmitDeliverable<'info> {
+pub struct ProposeOrgUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", organization.org_id.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Account<'info, Organization>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + OrgUpdateProposal::INIT_SPACE,
+        seeds = [b"org-update", organization.key().as_ref(), &organization.update_nonce.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, OrgUpdateProposal>,
     #[account(mut)]
-    pub contract: Account<'info, Contract>,
-    pub freelancer: Signer<'info>,
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct RequestRevision<'info> {
+pub struct SignOrgUpdate<'info> {
+    pub organization: Account<'info, Organization>,
+    #[account(
+        mut,
+        has_one = organization,
+        seeds = [b"org-update", organization.key().as_ref(), &proposal.nonce.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, OrgUpdateProposal>,
+    pub member: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteOrgUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", organization.org_id.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Account<'info, Organization>,
+    #[account(
+        mut,
+        has_one = organization,
+        seeds = [b"org-update", organization.key().as_ref(), &proposal.nonce.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, OrgUpdateProposal>,
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AssignContractToOrganization<'info> {
     #[account(mut)]
     pub contract: Account<'info, Contract>,
+    #[account(
+        seeds = [b"organization", organization.org_id.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Account<'info, Organization>,
     pub client: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct ApproveMilestone<'info> {
+#[instruction(action_index: u8)]
+pub struct ProposeOrgApproval<'info> {
+    pub contract: Account<'info, Contract>,
+    pub organization: Account<'info, Organization>,
+    #[account(
+        init,
+        payer = member,
+        space = 8 + OrgApproval::INIT_SPACE,
+        seeds = [b"org-approval", contract.key().as_ref(), &[action_index]],
+        bump
+    )]
+    pub approval: Account<'info, OrgApproval>,
     #[account(mut)]
+    pub member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SignOrgApproval<'info> {
+    pub contract: Account<'info, Contract>,
+    pub organization: Account<'info, Organization>,
+    #[account(
+        mut,
+        has_one = contract,
+        has_one = organization,
+        seeds = [b"org-approval", contract.key().as_ref(), &[approval.action_index]],
+        bump = approval.bump
+    )]
+    pub approval: Account<'info, OrgApproval>,
+    pub member: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteOrgDeposit<'info> {
+    #[account(
+        mut,
+        constraint = contract.organization == Some(organization.key()) @ ErrorCode::UnauthorizedOrgMember
+    )]
     pub contract: Account<'info, Contract>,
+    #[account(
+        seeds = [b"organization", organization.org_id.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Account<'info, Organization>,
+    #[account(
+        mut,
+        has_one = contract,
+        has_one = organization
+    )]
+    pub approval: Account<'info, OrgApproval>,
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == organization.key() @ ErrorCode::UnauthorizedOrgMember
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
     #[account(mut)]
-    pub client: Signer<'info>,
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    pub executor: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteOrgMilestoneApproval<'info> {
+    #[account(
+        mut,
+        constraint = contract.organization == Some(organization.key()) @ ErrorCode::UnauthorizedOrgMember
+    )]
+    pub contract: Account<'info, Contract>,
+    pub organization: Account<'info, Organization>,
+    #[account(
+        mut,
+        has_one = contract,
+        has_one = organization
+    )]
+    pub approval: Account<'info, OrgApproval>,
     #[account(mut)]
     pub escrow_token_account: Account<'info, TokenAccount>,
     #[account(mut)]
-    pub freelancer_token_account: Account<'info, TokenAccount>,
+    pub freelancer_token_account: Option<Account<'info, TokenAccount>>,
+    pub executor: Signer<'info>,
     pub token_program: Program<'info, Token>,
 }
 
@@ -406,6 +3152,11 @@ pub struct ApproveMilestone<'info> {
 pub struct OpenDispute<'info> {
     #[account(mut)]
     pub contract: Account<'info, Contract>,
+    #[account(
+        seeds = [b"arbitration-config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ArbitrationConfig>,
     #[account(
         init,
         payer = initiator,
@@ -427,8 +3178,186 @@ pub struct SubmitArbitratorVote<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(_contract_id: String, _milestone_index: u8, session_nonce: u64)]
+pub struct InitializeArbitrationConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ArbitrationConfig::INIT_SPACE,
+        seeds = [b"arbitration-config"],
+        bump
+    )]
+    pub config: Account<'info, ArbitrationConfig>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::Credchain>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateArbitrationConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"arbitration-config"],
+        bump = config.bump,
+        has_one = admin
+    )]
+    pub config: Account<'info, ArbitrationConfig>,
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitDisputeEvidence<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    #[account(
+        seeds = [b"arbitration-config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ArbitrationConfig>,
+    pub party: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AdvanceDispute<'info> {
+    #[account(
+        mut,
+        has_one = contract
+    )]
+    pub dispute: Account<'info, Dispute>,
+    pub contract: Account<'info, Contract>,
+    #[account(
+        seeds = [b"arbitration-config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ArbitrationConfig>,
+    pub cranker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteDisputeRuling<'info> {
+    #[account(
+        has_one = contract
+    )]
+    pub dispute: Account<'info, Dispute>,
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    #[account(
+        seeds = [b"arbitration-config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ArbitrationConfig>,
+    #[account(
+        mut,
+        constraint = escrow_token_account.owner == contract.key() @ ErrorCode::InvalidEscrowAccount,
+        constraint = escrow_token_account.mint == contract.payment_token @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = client_token_account.owner == contract.refund_owner() @ ErrorCode::UnauthorizedClient
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = freelancer_token_account.owner == contract.freelancer @ ErrorCode::UnauthorizedFreelancer
+    )]
+    pub freelancer_token_account: Option<Account<'info, TokenAccount>>,
+    pub cranker: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeSettlement<'info> {
+    #[account(mut)]
+    pub dispute: Account<'info, Dispute>,
+    pub proposer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptSettlement<'info> {
+    #[account(
+        mut,
+        has_one = contract
+    )]
+    pub dispute: Account<'info, Dispute>,
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+    #[account(
+        seeds = [b"arbitration-config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ArbitrationConfig>,
+    #[account(
+        mut,
+        constraint = escrow_token_account.owner == contract.key() @ ErrorCode::InvalidEscrowAccount,
+        constraint = escrow_token_account.mint == contract.payment_token @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = client_token_account.owner == contract.refund_owner() @ ErrorCode::UnauthorizedClient
+    )]
+    pub client_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = freelancer_token_account.owner == contract.freelancer @ ErrorCode::UnauthorizedFreelancer
+    )]
+    pub freelancer_token_account: Option<Account<'info, TokenAccount>>,
+    pub acceptor: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct InitializeReputation<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Reputation::INIT_SPACE,
+        seeds = [b"reputation", user.as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitReview<'info> {
+    pub contract: Account<'info, Contract>,
+    #[account(
+        init,
+        payer = reviewer,
+        space = 8 + Review::INIT_SPACE,
+        seeds = [b"review", contract.key().as_ref(), reviewer.key().as_ref()],
+        bump
+    )]
+    pub review: Account<'info, Review>,
+    #[account(
+        mut,
+        seeds = [b"reputation", reputation.user.as_ref()],
+        bump = reputation.bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(_contract_id: String, milestone_index: u8, session_nonce: u64)]
 pub struct StartTimeSession<'info> {
+    #[account(
+        mut,
+        constraint = matches!(contract.status, ContractStatus::Funded | ContractStatus::InProgress) @ ErrorCode::InvalidContractStatus,
+        constraint = (milestone_index as usize) < contract.milestones.len() @ ErrorCode::InvalidMilestoneIndex
+    )]
     pub contract: Account<'info, Contract>,
     #[account(
         init,
@@ -438,7 +3367,10 @@ pub struct StartTimeSession<'info> {
         bump
     )]
     pub session: Account<'info, TimeSession>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = contract.is_assigned(milestone_index, &freelancer.key()) @ ErrorCode::UnauthorizedFreelancer
+    )]
     pub freelancer: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
@@ -465,16 +3397,155 @@ pub struct Contract {
     pub freelancer: Pubkey,
     pub total_amount: u64,
     pub paid_amount: u64,
+    pub bonus_paid_amount: u64,
     pub payment_token: Pubkey,
     pub status: ContractStatus,
     pub created_at: i64,
     pub nda_signed_client: bool,
     pub nda_signed_freelancer: bool,
+    pub nda_signed_client_at: i64,
+    pub nda_signed_freelancer_at: i64,
+    pub nda_required: bool,
+    pub nda_hash: [u8; 32],
     #[max_len(5)]  
     pub milestones: Vec<Milestone>,
+    #[max_len(5)]
+    pub contributors: Vec<Contributor>,
+    pub parent_contract: Option<Pubkey>,
+    pub parent_milestone_index: u8,
+    pub contract_type: ContractType,
+    pub sequential: bool,
+    pub organization: Option<Pubkey>,
+    #[max_len(3)]
+    pub delegates: Vec<Delegate>,
+    pub job: Option<Pubkey>,
+    pub time_session_count: u32,
     pub bump: u8,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Delegate {
+    pub wallet: Pubkey,
+    pub role: DelegateRole,
+    pub spending_limit: u64,
+    pub spent: u64,
+}
+
+impl Contract {
+    pub fn validate_submission(&self, milestone_index: u8, submitter: &Pubkey) -> Result<()> {
+        require!((milestone_index as usize) < self.milestones.len(), ErrorCode::InvalidMilestoneIndex);
+        require!(self.is_assigned(milestone_index, submitter), ErrorCode::UnauthorizedFreelancer);
+        require!(self.prerequisites_met(milestone_index), ErrorCode::MilestonePrerequisitesIncomplete);
+        require!(self.nda_satisfied(), ErrorCode::NdaNotSigned);
+        require!(
+            self.milestones[milestone_index as usize].status == MilestoneStatus::Pending,
+            ErrorCode::InvalidMilestoneStatus
+        );
+        Ok(())
+    }
+
+    pub fn escrowed_amount(&self, status_before_dispute: &ContractStatus) -> u64 {
+        if *status_before_dispute == ContractStatus::Active {
+            return 0;
+        }
+
+        let subcontracted: u64 = self
+            .milestones
+            .iter()
+            .filter(|m| m.status != MilestoneStatus::Completed)
+            .map(|m| m.subcontracted_amount)
+            .sum();
+        self.total_amount - self.paid_amount - subcontracted
+    }
+
+    pub fn from_legacy(legacy: LegacyContract) -> Contract {
+        Contract {
+            contract_id: legacy.contract_id,
+            title: legacy.title,
+            description: legacy.description,
+            client: legacy.client,
+            freelancer: legacy.freelancer,
+            total_amount: legacy.total_amount,
+            paid_amount: legacy.paid_amount,
+            bonus_paid_amount: 0,
+            payment_token: legacy.payment_token,
+            status: legacy.status,
+            created_at: legacy.created_at,
+            nda_signed_client: legacy.nda_signed_client,
+            nda_signed_freelancer: legacy.nda_signed_freelancer,
+            nda_signed_client_at: 0,
+            nda_signed_freelancer_at: 0,
+            nda_required: false,
+            nda_hash: [0u8; 32],
+            milestones: legacy.milestones.into_iter().map(Milestone::from_legacy).collect(),
+            contributors: vec![],
+            parent_contract: None,
+            parent_milestone_index: 0,
+            contract_type: ContractType::FixedPrice,
+            sequential: false,
+            organization: None,
+            delegates: vec![],
+            job: None,
+            time_session_count: 0,
+            bump: legacy.bump,
+        }
+    }
+
+    pub fn refund_owner(&self) -> Pubkey {
+        self.organization.unwrap_or(self.client)
+    }
+
+    pub fn nda_satisfied(&self) -> bool {
+        !self.nda_required || (self.nda_signed_client && self.nda_signed_freelancer)
+    }
+
+    pub fn payout_shares(&self, milestone_index: u8) -> &[Contributor] {
+        let milestone = &self.milestones[milestone_index as usize];
+        if milestone.contributor_shares.is_empty() {
+            &self.contributors
+        } else {
+            &milestone.contributor_shares
+        }
+    }
+
+    pub fn prerequisites_met(&self, milestone_index: u8) -> bool {
+        let milestone = &self.milestones[milestone_index as usize];
+
+        let previous_completed = !self.sequential || self.milestones[..milestone_index as usize]
+            .iter()
+            .all(|m| m.status == MilestoneStatus::Completed);
+        let dependencies_completed = milestone
+            .depends_on
+            .iter()
+            .all(|d| self.milestones[*d as usize].status == MilestoneStatus::Completed);
+
+        previous_completed && dependencies_completed
+    }
+
+    pub fn has_dependency_cycle(&self) -> bool {
+        let mut resolved = vec![false; self.milestones.len()];
+
+        for _ in 0..self.milestones.len() {
+            for (i, milestone) in self.milestones.iter().enumerate() {
+                if !resolved[i] && milestone.depends_on.iter().all(|d| resolved[*d as usize]) {
+                    resolved[i] = true;
+                }
+            }
+        }
+
+        resolved.iter().any(|r| !r)
+    }
+
+    pub fn is_assigned(&self, milestone_index: u8, wallet: &Pubkey) -> bool {
+        let shares = self.payout_shares(milestone_index);
+        if shares.is_empty() {
+            *wallet == self.freelancer
+        } else {
+            shares.iter().any(|s| s.wallet == *wallet)
+        }
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct Milestone {
     pub index: u8,
@@ -489,6 +3560,117 @@ pub struct Milestone {
     pub revision_count: u8,
     #[max_len(3)]  
     pub deliverables: Vec<Deliverable>,
+    pub conditional_bonus: u64,
+    pub bonus_paid: u64,
+    #[max_len(5)]
+    pub contributor_shares: Vec<Contributor>,
+    pub subcontracted_amount: u64,
+    pub open_subcontracts: u8,
+    #[max_len(4)]
+    pub depends_on: Vec<u8>,
+    pub encrypted: bool,
+    pub key_commitment: [u8; 32],
+    pub decryption_key: Option<[u8; 32]>,
+    pub key_reveal_deadline: i64,
+    pub approved_by: Pubkey,
+}
+
+impl Milestone {
+    pub fn from_data(index: u8, data: &MilestoneData) -> Milestone {
+        Milestone {
+            index,
+            title: data.title.clone(),
+            description: data.description.clone(),
+            amount: data.amount,
+            deadline: data.deadline,
+            status: MilestoneStatus::Pending,
+            completed_at: 0,
+            revision_count: 0,
+            deliverables: vec![],
+            conditional_bonus: 0,
+            bonus_paid: 0,
+            contributor_shares: vec![],
+            subcontracted_amount: 0,
+            open_subcontracts: 0,
+            depends_on: vec![],
+            encrypted: false,
+            key_commitment: [0u8; 32],
+            decryption_key: None,
+            key_reveal_deadline: 0,
+            approved_by: Pubkey::default(),
+        }
+    }
+
+    pub fn from_legacy(legacy: LegacyMilestone) -> Milestone {
+        Milestone {
+            index: legacy.index,
+            title: legacy.title,
+            description: legacy.description,
+            amount: legacy.amount,
+            deadline: legacy.deadline,
+            status: legacy.status,
+            completed_at: legacy.completed_at,
+            revision_count: legacy.revision_count,
+            deliverables: legacy.deliverables,
+            conditional_bonus: 0,
+            bonus_paid: 0,
+            contributor_shares: vec![],
+            subcontracted_amount: 0,
+            open_subcontracts: 0,
+            depends_on: vec![],
+            encrypted: false,
+            key_commitment: [0u8; 32],
+            decryption_key: None,
+            key_reveal_deadline: 0,
+            approved_by: Pubkey::default(),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Contributor {
+    pub wallet: Pubkey,
+    pub share_bps: u16,
+}
+
+impl Contributor {
+    pub fn validate_shares(shares: &[Contributor]) -> Result<()> {
+        let total: u32 = shares.iter().map(|s| s.share_bps as u32).sum();
+        require!(total == 10_000, ErrorCode::InvalidContributorShares);
+
+        for (i, share) in shares.iter().enumerate() {
+            require!(
+                !shares[..i].iter().any(|s| s.wallet == share.wallet),
+                ErrorCode::InvalidContributorShares
+            );
+        }
+        Ok(())
+    }
+
+    pub fn split(shares: &[Contributor], amount: u64) -> Vec<u64> {
+        let mut payouts: Vec<u64> = shares
+            .iter()
+            .map(|s| (amount as u128 * s.share_bps as u128 / 10_000) as u64)
+            .collect();
+
+        let distributed: u64 = payouts.iter().sum();
+        if let Some(last) = payouts.last_mut() {
+            *last += amount - distributed;
+        }
+        payouts
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MilestoneDependency {
+    pub milestone_index: u8,
+    pub depends_on: Vec<u8>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MilestoneShares {
+    pub milestone_index: u8,
+    pub shares: Vec<Contributor>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
@@ -512,11 +3694,135 @@ pub struct MilestoneData {
     pub deadline: i64,
 }
 
+#[account]
+#[derive(InitSpace)]
+pub struct Organization {
+    #[max_len(32)]
+    pub org_id: String,
+    #[max_len(64)]
+    pub name: String,
+    #[max_len(10)]
+    pub members: Vec<OrgMember>,
+    pub threshold: u8,
+    pub update_nonce: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Organization {
+    pub fn validate_members(members: &[OrgMember], threshold: u8) -> Result<()> {
+        require!(members.len() > 0 && members.len() <= 10, ErrorCode::InvalidOrgMembers);
+        for (i, member) in members.iter().enumerate() {
+            require!(
+                !members[..i].iter().any(|m| m.wallet == member.wallet),
+                ErrorCode::InvalidOrgMembers
+            );
+        }
+
+        let approvers = members.iter().filter(|m| m.role != OrgRole::Reviewer).count();
+        require!(
+            threshold > 0 && (threshold as usize) <= approvers,
+            ErrorCode::InvalidOrgThreshold
+        );
+        Ok(())
+    }
+
+    pub fn role_of(&self, wallet: &Pubkey) -> Option<OrgRole> {
+        self.members.iter().find(|m| m.wallet == *wallet).map(|m| m.role.clone())
+    }
+
+    pub fn can_approve(&self, wallet: &Pubkey) -> bool {
+        matches!(self.role_of(wallet), Some(OrgRole::Admin) | Some(OrgRole::Approver))
+    }
+
+    pub fn threshold_met(&self, approvers: &[Pubkey]) -> bool {
+        let valid = approvers.iter().filter(|a| self.can_approve(a)).count();
+        valid >= self.threshold as usize
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct OrgMember {
+    pub wallet: Pubkey,
+    pub role: OrgRole,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct OrgApproval {
+    pub contract: Pubkey,
+    pub organization: Pubkey,
+    pub action_index: u8,
+    #[max_len(10)]
+    pub approvers: Vec<Pubkey>,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct OrgUpdateProposal {
+    pub organization: Pubkey,
+    pub nonce: u64,
+    #[max_len(10)]
+    pub members: Vec<OrgMember>,
+    pub threshold: u8,
+    #[max_len(10)]
+    pub approvers: Vec<Pubkey>,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Retainer {
+    pub contract: Pubkey,
+    pub period_amount: u64,
+    pub period_length: i64,
+    pub period_index: u32,
+    pub period_start: i64,
+    pub funded_periods: u32,
+    pub objection_raised: bool,
+    #[max_len(200)]
+    pub objection_reason: String,
+    pub notice_given_by: Option<Pubkey>,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Stream {
+    pub contract: Pubkey,
+    pub deposited: u64,
+    pub withdrawn: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub stopped_at: i64,
+    pub bump: u8,
+}
+
+impl Stream {
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        let cutoff = if self.stopped_at > 0 { now.min(self.stopped_at) } else { now };
+        if cutoff <= self.start_time {
+            return 0;
+        }
+        if cutoff >= self.end_time {
+            return self.deposited;
+        }
+
+        let elapsed = (cutoff - self.start_time) as u128;
+        let duration = (self.end_time - self.start_time) as u128;
+        (self.deposited as u128 * elapsed / duration) as u64
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Dispute {
     pub contract: Pubkey,
     pub initiator: Pubkey,
+    pub respondent: Pubkey,
     pub category: DisputeCategory,
     #[max_len(100)]
     pub reason: String,
@@ -524,13 +3830,35 @@ pub struct Dispute {
     pub description: String,
     pub status: DisputeStatus,
     pub created_at: i64,
+    pub phase_deadline: i64,
+    #[max_len(2)]
+    pub evidence: Vec<DisputeEvidence>,
     #[max_len(3)]
     pub arbitrators: Vec<Pubkey>,
     #[max_len(3)]
     pub votes: Vec<ArbitratorVote>,
+    pub reassignment_count: u8,
+    pub resolved_at: i64,
+    pub settlement: Option<SettlementOffer>,
+    pub contract_status: ContractStatus,
     pub bump: u8,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct SettlementOffer {
+    pub proposer: Pubkey,
+    pub client_share_bps: u16,
+    pub proposed_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct DisputeEvidence {
+    pub party: Pubkey,
+    #[max_len(64)]
+    pub ipfs_hash: String,
+    pub submitted_at: i64,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct ArbitratorVote {
     pub arbitrator: Pubkey,
@@ -540,6 +3868,66 @@ pub struct ArbitratorVote {
     pub voted_at: i64,
 }
 
+#[account]
+#[derive(InitSpace)]
+pub struct ArbitrationConfig {
+    pub admin: Pubkey,
+    pub evidence_period: i64,
+    pub voting_period: i64,
+    pub max_reassignments: u8,
+    pub arbitrator_fee: u64,
+    #[max_len(20)]
+    pub arbitrator_pool: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl ArbitrationConfig {
+    pub fn draw_arbitrators(&self, excluded: &[Pubkey], count: usize, seed: u64) -> Vec<Pubkey> {
+        let eligible: Vec<Pubkey> = self
+            .arbitrator_pool
+            .iter()
+            .filter(|a| !excluded.contains(a))
+            .cloned()
+            .collect();
+
+        if eligible.is_empty() {
+            return vec![];
+        }
+
+        let start = (seed % eligible.len() as u64) as usize;
+        eligible
+            .iter()
+            .cycle()
+            .skip(start)
+            .take(count.min(eligible.len()))
+            .cloned()
+            .collect()
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Review {
+    pub contract: Pubkey,
+    pub reviewer: Pubkey,
+    pub reviewee: Pubkey,
+    pub score: u8,
+    pub comment_hash: [u8; 32],
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Reputation {
+    pub user: Pubkey,
+    pub review_count: u32,
+    pub total_score: u64,
+    pub average_score: u16,
+    pub last_review_at: i64,
+    pub bump: u8,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct TimeSession {
@@ -554,8 +3942,78 @@ pub struct TimeSession {
     pub bump: u8,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyContract {
+    pub contract_id: String,
+    pub title: String,
+    pub description: String,
+    pub client: Pubkey,
+    pub freelancer: Pubkey,
+    pub total_amount: u64,
+    pub paid_amount: u64,
+    pub payment_token: Pubkey,
+    pub status: ContractStatus,
+    pub created_at: i64,
+    pub nda_signed_client: bool,
+    pub nda_signed_freelancer: bool,
+    pub milestones: Vec<LegacyMilestone>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyMilestone {
+    pub index: u8,
+    pub title: String,
+    pub description: String,
+    pub amount: u64,
+    pub deadline: i64,
+    pub status: MilestoneStatus,
+    pub completed_at: i64,
+    pub revision_count: u8,
+    pub deliverables: Vec<Deliverable>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyDispute {
+    pub contract: Pubkey,
+    pub initiator: Pubkey,
+    pub category: DisputeCategory,
+    pub reason: String,
+    pub description: String,
+    pub status: DisputeStatus,
+    pub created_at: i64,
+    pub arbitrators: Vec<Pubkey>,
+    pub votes: Vec<ArbitratorVote>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct JobBoardJob {
+    pub job_id: String,
+    pub title: String,
+    pub description: String,
+    pub employer: Pubkey,
+    pub budget_min: u64,
+    pub budget_max: u64,
+    pub job_type: u8,
+    pub duration: String,
+    pub location: String,
+    pub required_badges: Vec<u8>,
+    pub status: u8,
+    pub created_at: i64,
+    pub applicant_count: u32,
+    pub selected_freelancer: Option<Pubkey>,
+    pub contract: Option<Pubkey>,
+}
+
 
 
+pub const JOB_BOARD_PROGRAM_ID: Pubkey = pubkey!("mUfeb5rs5gH8n92VCqbuVNWPaU333tM6BhKZvTFEfvd");
+pub const ARBITRATORS_PER_DISPUTE: usize = 3;
+pub const KEY_REVEAL_WINDOW: i64 = 3 * 24 * 60 * 60;
+pub const RETAINER_OBJECTION_WINDOW: i64 = 7 * 24 * 60 * 60;
+pub const FUNDING_ACTION_INDEX: u8 = u8::MAX;
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
 pub enum ContractStatus {
     Active,
@@ -566,12 +4024,34 @@ pub enum ContractStatus {
     Cancelled,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum ContractType {
+    FixedPrice,
+    Retainer,
+    Stream,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
 pub enum MilestoneStatus {
     Pending,
     UnderReview,
     RevisionRequested,
     Completed,
+    AwaitingKey,
+    KeyMismatch,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum DelegateRole {
+    Reviewer,
+    Approver,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum OrgRole {
+    Admin,
+    Approver,
+    Reviewer,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
@@ -591,6 +4071,7 @@ pub enum DisputeStatus {
     ResolvedForClient,
     ResolvedForFreelancer,
     Cancelled,
+    Settled,
 }
 
 
@@ -601,6 +4082,7 @@ pub struct RevisionRequestedEvent {
     pub milestone_index: u8,
     pub reason: String,
     pub revision_count: u8,
+    pub requested_by: Pubkey,
 }
 
 #[event]
@@ -608,6 +4090,155 @@ pub struct MilestoneApprovedEvent {
     pub contract_id: String,
     pub milestone_index: u8,
     pub amount: u64,
+    pub approved_by: Pubkey,
+}
+
+#[event]
+pub struct DelegateUpdatedEvent {
+    pub contract_id: String,
+    pub delegate: Pubkey,
+    pub role: Option<DelegateRole>,
+    pub spending_limit: u64,
+}
+
+#[event]
+pub struct BonusPaidEvent {
+    pub contract_id: String,
+    pub milestone_index: u8,
+    pub freelancer: Pubkey,
+    pub amount: u64,
+    pub conditional: bool,
+}
+
+#[event]
+pub struct TeamConfiguredEvent {
+    pub contract_id: String,
+    pub contributors: Vec<Pubkey>,
+}
+
+#[event]
+pub struct ContributorPaidEvent {
+    pub contract_id: String,
+    pub milestone_index: u8,
+    pub contributor: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SubcontractCreatedEvent {
+    pub parent_contract_id: String,
+    pub parent_milestone_index: u8,
+    pub contract_id: String,
+    pub freelancer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RetainerPeriodFundedEvent {
+    pub contract_id: String,
+    pub period_index: u32,
+    pub periods: u32,
+    pub amount: u64,
+    pub period_end: i64,
+}
+
+#[event]
+pub struct RetainerPeriodClaimedEvent {
+    pub contract_id: String,
+    pub period_index: u32,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RetainerObjectionEvent {
+    pub contract_id: String,
+    pub period_index: u32,
+    pub reason: String,
+}
+
+#[event]
+pub struct RetainerNoticeEvent {
+    pub contract_id: String,
+    pub given_by: Pubkey,
+    pub final_period_index: u32,
+}
+
+#[event]
+pub struct StreamCreatedEvent {
+    pub contract_id: String,
+    pub amount: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct StreamWithdrawnEvent {
+    pub contract_id: String,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+}
+
+#[event]
+pub struct StreamStoppedEvent {
+    pub contract_id: String,
+    pub vested: u64,
+    pub refunded: u64,
+}
+
+#[event]
+pub struct NdaSignatureEvent {
+    pub contract_id: String,
+    pub signer: Pubkey,
+    pub signed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KeyRevealRequestedEvent {
+    pub contract_id: String,
+    pub milestone_index: u8,
+    pub reveal_deadline: i64,
+}
+
+#[event]
+pub struct KeyRevealedEvent {
+    pub contract_id: String,
+    pub milestone_index: u8,
+    pub decryption_key: [u8; 32],
+}
+
+#[event]
+pub struct KeyMismatchEvent {
+    pub contract_id: String,
+    pub milestone_index: u8,
+    pub revealed_by: Pubkey,
+}
+
+#[event]
+pub struct OrgApprovalSignedEvent {
+    pub contract_id: String,
+    pub action_index: u8,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct OrgUpdateSignedEvent {
+    pub organization: Pubkey,
+    pub nonce: u64,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ReviewSubmittedEvent {
+    pub contract_id: String,
+    pub reviewer: Pubkey,
+    pub reviewee: Pubkey,
+    pub score: u8,
+    pub average_score: u16,
 }
 
 #[event]
@@ -617,6 +4248,46 @@ pub struct DisputeOpenedEvent {
     pub category: DisputeCategory,
 }
 
+#[event]
+pub struct ArbitratorsAssignedEvent {
+    pub dispute: Pubkey,
+    pub arbitrators: Vec<Pubkey>,
+    pub voting_deadline: i64,
+}
+
+#[event]
+pub struct DisputeResolvedEvent {
+    pub dispute: Pubkey,
+    pub status: DisputeStatus,
+    pub default_judgment: bool,
+}
+
+#[event]
+pub struct DisputeRulingExecutedEvent {
+    pub contract_id: String,
+    pub dispute: Pubkey,
+    pub status: DisputeStatus,
+    pub client_amount: u64,
+    pub freelancer_amount: u64,
+    pub arbitrator_fees: u64,
+}
+
+#[event]
+pub struct SettlementProposedEvent {
+    pub dispute: Pubkey,
+    pub proposer: Pubkey,
+    pub client_share_bps: u16,
+}
+
+#[event]
+pub struct SettlementAcceptedEvent {
+    pub contract_id: String,
+    pub dispute: Pubkey,
+    pub client_amount: u64,
+    pub freelancer_amount: u64,
+    pub arbitrator_fees: u64,
+}
+
 
 
 #[error_code]
@@ -647,4 +4318,118 @@ pub enum ErrorCode {
     UnauthorizedArbitrator,
     #[msg("Already voted")]
     AlreadyVoted,
+    #[msg("Invalid dispute period")]
+    InvalidDisputePeriod,
+    #[msg("Invalid arbitrator pool")]
+    InvalidArbitratorPool,
+    #[msg("Dispute phase deadline has passed")]
+    DisputePhaseExpired,
+    #[msg("Dispute phase deadline has not passed")]
+    DisputePhaseNotExpired,
+    #[msg("Evidence already submitted")]
+    EvidenceAlreadySubmitted,
+    #[msg("Invalid settlement split (max 10000 bps)")]
+    InvalidSettlementSplit,
+    #[msg("No settlement offer to accept")]
+    NoSettlementOffer,
+    #[msg("Settlement offer does not match the expected split")]
+    SettlementOfferChanged,
+    #[msg("Invalid arbitrator token account")]
+    InvalidArbitratorTokenAccount,
+    #[msg("Invalid bonus amount")]
+    InvalidBonusAmount,
+    #[msg("Milestone deadline has passed")]
+    MilestoneDeadlinePassed,
+    #[msg("Invalid contributor count (1-5 allowed)")]
+    InvalidContributorCount,
+    #[msg("Contributor shares must be unique and sum to 10000 bps")]
+    InvalidContributorShares,
+    #[msg("Milestone share references an unknown contributor")]
+    UnknownContributor,
+    #[msg("Invalid contributor token account")]
+    InvalidContributorTokenAccount,
+    #[msg("Subcontract exceeds the unallocated milestone amount")]
+    SubcontractExceedsMilestone,
+    #[msg("Milestone has unsettled subcontracts")]
+    SubcontractsOutstanding,
+    #[msg("Contract is not a subcontract of this parent")]
+    NotASubcontract,
+    #[msg("Instruction not supported for this contract type")]
+    InvalidContractType,
+    #[msg("Invalid retainer period length")]
+    InvalidRetainerPeriod,
+    #[msg("Retainer period not funded")]
+    RetainerPeriodNotFunded,
+    #[msg("Retainer period has not elapsed")]
+    RetainerPeriodNotElapsed,
+    #[msg("Client has raised an objection for this retainer period")]
+    RetainerObjectionRaised,
+    #[msg("Retainer objections can only be raised while the period is open")]
+    RetainerObjectionWindowClosed,
+    #[msg("No retainer objection to withdraw")]
+    NoRetainerObjection,
+    #[msg("Retainer notice already given")]
+    RetainerNoticeGiven,
+    #[msg("Invalid stream schedule (end must be after start)")]
+    InvalidStreamSchedule,
+    #[msg("Nothing vested to withdraw")]
+    NothingToWithdraw,
+    #[msg("Stream already stopped")]
+    StreamAlreadyStopped,
+    #[msg("Stream has already ended")]
+    StreamAlreadyEnded,
+    #[msg("Invalid milestone dependency")]
+    InvalidMilestoneDependency,
+    #[msg("Milestone prerequisites are not completed")]
+    MilestonePrerequisitesIncomplete,
+    #[msg("NDA hash does not match the contract")]
+    InvalidNdaHash,
+    #[msg("NDA has not been signed by both parties")]
+    NdaNotSigned,
+    #[msg("NDA signature cannot be revoked after work has started")]
+    NdaWorkStarted,
+    #[msg("Decryption key does not match the committed hash")]
+    KeyCommitmentMismatch,
+    #[msg("Milestone requires encrypted deliverables")]
+    EncryptedMilestone,
+    #[msg("Key reveal window is still open")]
+    KeyRevealWindowOpen,
+    #[msg("Invalid organization members (1-10 unique members required)")]
+    InvalidOrgMembers,
+    #[msg("Invalid organization approval threshold")]
+    InvalidOrgThreshold,
+    #[msg("Signer is not an authorized organization member")]
+    UnauthorizedOrgMember,
+    #[msg("Organization approval already executed")]
+    OrgApprovalExecuted,
+    #[msg("Organization approval threshold not met")]
+    OrgThresholdNotMet,
+    #[msg("Organization approval is for a different action")]
+    InvalidOrgApproval,
+    #[msg("Organization contracts must be approved through an organization approval")]
+    OrgApprovalRequired,
+    #[msg("Organization update has been superseded by a newer proposal")]
+    StaleOrgUpdate,
+    #[msg("Invalid delegate")]
+    InvalidDelegate,
+    #[msg("Delegate spending limit exceeded")]
+    DelegateLimitExceeded,
+    #[msg("Invalid review score (1-5)")]
+    InvalidReviewScore,
+    #[msg("Reputation account does not belong to the reviewee")]
+    InvalidReputationAccount,
+    #[msg("Contract is already linked to a job")]
+    JobAlreadyLinked,
+    #[msg("Signer is not the program upgrade authority")]
+    UnauthorizedAdmin,
+    #[msg("Invalid escrow token account")]
+    InvalidEscrowAccount,
+    #[msg("Job account is not the job_board job for this contract")]
+    InvalidJobAccount,
+    #[msg("Account already uses the current layout")]
+    AccountAlreadyMigrated,
+    #[msg("Dispute does not belong to this contract")]
+    InvalidDisputeAccount,
+    #[msg("Arithmetic overflow")]
+    Overflow,
 }
\ No newline at end of file
@@ -1,4 +1,17 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{Token, TokenAccount};
+use credchain::{
+    cpi::accounts::{
+        CreateContract as CredchainCreateContract,
+        DepositEscrow as CredchainDepositEscrow,
+        LinkJob as CredchainLinkJob,
+    },
+    program::Credchain,
+    Contract as CredchainContract,
+    ContractStatus as CredchainContractStatus,
+    MilestoneData,
+};
 
 declare_id!("mUfeb5rs5gH8n92VCqbuVNWPaU333tM6BhKZvTFEfvd");
 
@@ -53,6 +66,7 @@ pub mod job_board {
         job.created_at = Clock::get()?.unix_timestamp;
         job.applicant_count = 0;
         job.selected_freelancer = None;
+        job.contract = None;
         job.bump = ctx.bumps.job;
 
         
@@ -158,6 +172,161 @@ pub mod job_board {
     }
 
     
+    pub fn create_contract_from_application(
+        ctx: Context<CreateContractFromApplication>,
+        contract_id: String,
+        milestones: Vec<MilestoneData>,
+        payment_token: Pubkey,
+        nda_required: bool,
+        nda_hash: [u8; 32],
+    ) -> Result<()> {
+        let job = &ctx.accounts.job;
+        let application = &ctx.accounts.application;
+
+        require!(ctx.accounts.employer.key() == job.employer, ErrorCode::UnauthorizedEmployer);
+        require!(job.status == JobStatus::InProgress, ErrorCode::InvalidJobStatus);
+        require!(job.contract.is_none(), ErrorCode::ContractAlreadyCreated);
+        require!(application.status == ApplicationStatus::Accepted, ErrorCode::InvalidApplicationStatus);
+        require!(
+            job.selected_freelancer == Some(application.freelancer),
+            ErrorCode::InvalidApplicationStatus
+        );
+
+        let total_amount: u64 = milestones.iter().map(|m| m.amount).sum();
+        require!(total_amount == application.proposed_budget, ErrorCode::BudgetMismatch);
+
+        let title = truncate(&job.title, 64);
+        let description = truncate(&job.description, 200);
+
+        credchain::cpi::create_contract(
+            CpiContext::new(
+                ctx.accounts.credchain_program.to_account_info(),
+                CredchainCreateContract {
+                    contract: ctx.accounts.contract.to_account_info(),
+                    client: ctx.accounts.employer.to_account_info(),
+                    freelancer: ctx.accounts.freelancer.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+            ),
+            contract_id.clone(),
+            title,
+            description,
+            total_amount,
+            milestones,
+            payment_token,
+            nda_required,
+            nda_hash,
+        )?;
+
+        let job_id = job.job_id.clone();
+        ctx.accounts.job.contract = Some(ctx.accounts.contract.key());
+        ctx.accounts.job.exit(ctx.program_id)?;
+
+        credchain::cpi::link_job(
+            CpiContext::new(
+                ctx.accounts.credchain_program.to_account_info(),
+                CredchainLinkJob {
+                    contract: ctx.accounts.contract.to_account_info(),
+                    job: ctx.accounts.job.to_account_info(),
+                    client: ctx.accounts.employer.to_account_info(),
+                },
+            ),
+            job_id,
+        )?;
+
+        if !nda_required {
+            credchain::cpi::deposit_escrow(
+                CpiContext::new(
+                    ctx.accounts.credchain_program.to_account_info(),
+                    CredchainDepositEscrow {
+                        contract: ctx.accounts.contract.to_account_info(),
+                        client: ctx.accounts.employer.to_account_info(),
+                        client_token_account: ctx.accounts.employer_token_account.to_account_info(),
+                        escrow_token_account: ctx.accounts.escrow_token_account.to_account_info(),
+                        token_program: ctx.accounts.token_program.to_account_info(),
+                    },
+                ),
+                total_amount,
+            )?;
+        }
+
+        let job = &ctx.accounts.job;
+
+        emit!(ContractCreatedFromJobEvent {
+            job_id: job.job_id.clone(),
+            contract_id,
+            contract: ctx.accounts.contract.key(),
+            freelancer: application.freelancer,
+            amount: total_amount,
+            funded: !nda_required,
+        });
+
+        msg!("Contract created for job: {}", job.job_id);
+        Ok(())
+    }
+
+    
+    pub fn migrate_job(ctx: Context<MigrateJob>) -> Result<()> {
+        let job_info = ctx.accounts.job.to_account_info();
+        let space = 8 + Job::INIT_SPACE;
+        require!(job_info.data_len() < space, ErrorCode::AccountAlreadyMigrated);
+
+        let legacy = {
+            let data = job_info.try_borrow_data()?;
+            require!(data[..8] == Job::DISCRIMINATOR[..], ErrorCode::AccountAlreadyMigrated);
+            LegacyJob::deserialize(&mut &data[8..])?
+        };
+        let job = Job::from_legacy(legacy);
+
+        let rent = Rent::get()?.minimum_balance(space);
+        let shortfall = rent.saturating_sub(job_info.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: job_info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        job_info.realloc(space, true)?;
+
+        let mut data = job_info.try_borrow_mut_data()?;
+        job.try_serialize(&mut &mut data[..])?;
+
+        msg!("Job migrated: {}", job.job_id);
+        Ok(())
+    }
+
+    
+    pub fn sync_job_completion(
+        ctx: Context<SyncJobCompletion>,
+    ) -> Result<()> {
+        let job = &mut ctx.accounts.job;
+        let contract = &ctx.accounts.contract;
+
+        require!(job.contract == Some(contract.key()), ErrorCode::ContractMismatch);
+        require!(job.status == JobStatus::InProgress, ErrorCode::InvalidJobStatus);
+        require!(
+            contract.status == CredchainContractStatus::Completed,
+            ErrorCode::ContractNotCompleted
+        );
+
+        job.status = JobStatus::Completed;
+
+        emit!(JobCompletedEvent {
+            job_id: job.job_id.clone(),
+            freelancer: contract.freelancer,
+        });
+
+        msg!("Job completed: {}", job.job_id);
+        Ok(())
+    }
+
+    
     pub fn reject_application(
         ctx: Context<RejectApplication>,
     ) -> Result<()> {
@@ -303,6 +472,59 @@ pub struct AcceptApplication<'info> {
     pub employer: Signer<'info>,
 }
 
+#[derive(Accounts)]
+#[instruction(_contract_id: String, _milestones: Vec<MilestoneData>, payment_token: Pubkey)]
+pub struct CreateContractFromApplication<'info> {
+    #[account(mut)]
+    pub job: Account<'info, Job>,
+
+    #[account(has_one = job)]
+    pub application: Account<'info, JobApplication>,
+
+    
+    #[account(mut)]
+    pub contract: UncheckedAccount<'info>,
+
+    
+    #[account(address = application.freelancer)]
+    pub freelancer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(mut)]
+    pub employer_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = escrow_token_account.owner == contract.key() @ ErrorCode::InvalidEscrowAccount,
+        constraint = escrow_token_account.mint == payment_token @ ErrorCode::InvalidEscrowAccount
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    pub credchain_program: Program<'info, Credchain>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateJob<'info> {
+    
+    #[account(mut, owner = crate::ID)]
+    pub job: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SyncJobCompletion<'info> {
+    #[account(mut)]
+    pub job: Account<'info, Job>,
+
+    pub contract: Account<'info, CredchainContract>,
+}
+
 #[derive(Accounts)]
 pub struct RejectApplication<'info> {
     pub job: Account<'info, Job>,
@@ -364,6 +586,7 @@ pub struct Job {
     pub created_at: i64,
     pub applicant_count: u32,
     pub selected_freelancer: Option<Pubkey>,
+    pub contract: Option<Pubkey>,
     pub bump: u8,
 }
 
@@ -384,8 +607,58 @@ pub struct JobApplication {
     pub bump: u8,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyJob {
+    pub job_id: String,
+    pub title: String,
+    pub description: String,
+    pub employer: Pubkey,
+    pub budget_min: u64,
+    pub budget_max: u64,
+    pub job_type: JobType,
+    pub duration: String,
+    pub location: String,
+    pub required_badges: Vec<SkillCategory>,
+    pub status: JobStatus,
+    pub created_at: i64,
+    pub applicant_count: u32,
+    pub selected_freelancer: Option<Pubkey>,
+    pub bump: u8,
+}
+
+impl Job {
+    pub fn from_legacy(legacy: LegacyJob) -> Job {
+        Job {
+            job_id: legacy.job_id,
+            title: legacy.title,
+            description: legacy.description,
+            employer: legacy.employer,
+            budget_min: legacy.budget_min,
+            budget_max: legacy.budget_max,
+            job_type: legacy.job_type,
+            duration: legacy.duration,
+            location: legacy.location,
+            required_badges: legacy.required_badges,
+            status: legacy.status,
+            created_at: legacy.created_at,
+            applicant_count: legacy.applicant_count,
+            selected_freelancer: legacy.selected_freelancer,
+            contract: None,
+            bump: legacy.bump,
+        }
+    }
+}
 
 
+
+fn truncate(value: &str, max_len: usize) -> String {
+    let mut end = value.len().min(max_len);
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    value[..end].to_string()
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
 pub enum SkillCategory {
     SolanaDeveloper,
@@ -465,6 +738,16 @@ pub struct ApplicationRejectedEvent {
     pub freelancer: Pubkey,
 }
 
+#[event]
+pub struct ContractCreatedFromJobEvent {
+    pub job_id: String,
+    pub contract_id: String,
+    pub contract: Pubkey,
+    pub freelancer: Pubkey,
+    pub amount: u64,
+    pub funded: bool,
+}
+
 #[event]
 pub struct JobClosedEvent {
     pub job_id: String,
@@ -506,4 +789,16 @@ pub enum ErrorCode {
     InvalidApplicationStatus,
     #[msg("Invalid job status")]
     InvalidJobStatus,
+    #[msg("A contract has already been created for this job")]
+    ContractAlreadyCreated,
+    #[msg("Milestone amounts must equal the accepted proposed budget")]
+    BudgetMismatch,
+    #[msg("Contract is not linked to this job")]
+    ContractMismatch,
+    #[msg("Contract is not completed")]
+    ContractNotCompleted,
+    #[msg("Invalid escrow token account")]
+    InvalidEscrowAccount,
+    #[msg("Account already uses the current layout")]
+    AccountAlreadyMigrated,
 }
\ No newline at end of file
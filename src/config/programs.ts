@@ -102,6 +102,7 @@ export const PDA_SEEDS = {
   CONTRACT: 'contract',
   DISPUTE: 'dispute',
   SESSION: 'session',
+  REPUTATION: 'reputation',
   AUTHORITY: 'authority',
   TEST_RESULT: 'test-result',
   BADGE: 'badge',
@@ -109,6 +110,8 @@ export const PDA_SEEDS = {
   LEADERBOARD: 'leaderboard',
   JOB_BADGE: 'job-badge',
   JOB_BADGE_MINT: 'job-badge-mint',
+  TIER_CONFIG: 'tier-config',
+  COLLECTION_MINT: 'collection-mint',
   JOB_BOARD_AUTHORITY: 'job-board-authority',
 };
 
@@ -29,6 +29,8 @@ interface ContractData {
     createdAt: BN;
     ndaSignedClient: boolean;
     ndaSignedFreelancer: boolean;
+    ndaRequired: boolean;
+    ndaHash: number[];
     milestones: any[];
     bump: number;
   };
@@ -162,7 +164,7 @@ export const ContractDetails = () => {
       setActionLoading(true);
 
       const tx = await credchainProgram.methods
-        .signNda()
+        .signNda(contract.account.ndaHash)
         .accounts({
           contract: contract.publicKey,
           signer: publicKey,
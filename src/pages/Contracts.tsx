@@ -6,6 +6,7 @@ import { Plus, FileText, Shield, Loader2 } from 'lucide-react';
 import { BN } from '@coral-xyz/anchor';
 import { usePrograms } from '../hooks/usePrograms';
 import { getContractPDA } from '../utils/pdaHelpers';
+import { hashNdaDocument } from '../utils/ndaHelpers';
 import { CONTRACT_CONFIG } from '../config/programs';
 import { CompletionNFT } from '../components/CompletionNFT';
 import { useToastContext } from '../components/Layout';
@@ -53,6 +54,8 @@ export const Contracts = () => {
     freelancerAddress: '',
     totalAmount: '',
     paymentToken: 'SOL',
+    ndaRequired: false,
+    ndaDocument: '',
   });
 
   
@@ -123,6 +126,11 @@ export const Contracts = () => {
       return;
     }
 
+    if (formData.ndaRequired && !formData.ndaDocument.trim()) {
+      toast.warning('Please provide the NDA document');
+      return;
+    }
+
     setLoading(true);
 
     try {
@@ -153,6 +161,10 @@ export const Contracts = () => {
         ? SystemProgram.programId
         : new PublicKey(formData.paymentToken);
 
+      const ndaHash = formData.ndaRequired
+        ? await hashNdaDocument(formData.ndaDocument)
+        : new Array(32).fill(0);
+
       console.log('Creating contract with:');
       console.log('- Contract ID:', contractId);
       console.log('- Title:', formData.title);
@@ -177,7 +189,9 @@ export const Contracts = () => {
           formData.description,
           totalAmount,
           programMilestones,
-          paymentToken
+          paymentToken,
+          formData.ndaRequired,
+          ndaHash
         )
         .accounts({
           contract: contractPDA,
@@ -201,6 +215,8 @@ export const Contracts = () => {
         freelancerAddress: '',
         totalAmount: '',
         paymentToken: 'SOL',
+        ndaRequired: false,
+        ndaDocument: '',
       });
       setMilestones([{ id: '1', title: '', description: '', amount: '', deadline: '' }]);
       setShowCreateForm(false);
@@ -332,6 +348,26 @@ export const Contracts = () => {
                   </select>
                 </div>
               </div>
+
+              <div>
+                <label className="flex items-center space-x-2 text-sm font-medium">
+                  <input
+                    type="checkbox"
+                    checked={formData.ndaRequired}
+                    onChange={(e) => setFormData({ ...formData, ndaRequired: e.target.checked })}
+                  />
+                  <span>Require both parties to sign an NDA before work starts</span>
+                </label>
+                {formData.ndaRequired && (
+                  <textarea
+                    value={formData.ndaDocument}
+                    onChange={(e) => setFormData({ ...formData, ndaDocument: e.target.value })}
+                    className="w-full bg-white/5 border border-white/10 rounded-lg px-4 py-3 mt-3 focus:outline-none focus:border-primary-500 h-32"
+                    placeholder="Paste the NDA text; its SHA-256 hash is stored on-chain"
+                    required
+                  />
+                )}
+              </div>
             </div>
 
             
@@ -5,9 +5,9 @@ import { PublicKey, SystemProgram } from '@solana/web3.js';
 import { Award, Code, Palette, PenTool, BarChart3, Megaphone, Clock, Shield, CheckCircle } from 'lucide-react';
 import { BN } from '@coral-xyz/anchor';
 import { usePrograms } from '../hooks/usePrograms';
-import { getBadgePDA, getBadgeMintPDA, getTestResultPDA, getMetadataPDA, getAssociatedTokenAddressPDA, getBadgeAuthorityPDA } from '../utils/pdaHelpers';
+import { getBadgePDA, getBadgeMintPDA, getTestResultPDA, getMetadataPDA, getAssociatedTokenAddressPDA, getBadgeAuthorityPDA, getTierConfigPDA, getCollectionMintPDA, getMasterEditionPDA } from '../utils/pdaHelpers';
 import { SkillCategory, SKILL_CATEGORY_NAMES, TEST_CONFIG } from '../config/programs';
-import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
 import { useToastContext } from '../components/Layout';
 import { SkillTestModal } from '../components/SkillTestModal';
 import { skillTests } from '../data/testQuestions';
@@ -200,8 +200,12 @@ export const Credentials = () => {
       const [badgePDA] = getBadgePDA(publicKey, selectedSkill.id);
       const [mintPDA] = getBadgeMintPDA(publicKey, selectedSkill.id);
       const [metadataPDA] = getMetadataPDA(mintPDA);
-      const [tokenAccountPDA] = getAssociatedTokenAddressPDA(publicKey, mintPDA);
+      const [tokenAccountPDA] = getAssociatedTokenAddressPDA(publicKey, mintPDA, TOKEN_2022_PROGRAM_ID);
       const [authorityPDA] = getBadgeAuthorityPDA();
+      const [tierConfigPDA] = getTierConfigPDA(selectedSkill.id);
+      const [collectionMintPDA] = getCollectionMintPDA(selectedSkill.id);
+      const [collectionMetadataPDA] = getMetadataPDA(collectionMintPDA);
+      const [collectionMasterEditionPDA] = getMasterEditionPDA(collectionMintPDA);
 
       const METADATA_PROGRAM_ID = new PublicKey('metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s');
       const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey('ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL');
@@ -218,12 +222,16 @@ export const Credentials = () => {
         .accounts({
           authority: authorityPDA, 
           testResult: testResultPDA,
+          tierConfig: tierConfigPDA,
           badge: badgePDA,
           mint: mintPDA,
           tokenAccount: tokenAccountPDA,
           metadata: metadataPDA,
+          collectionMint: collectionMintPDA,
+          collectionMetadata: collectionMetadataPDA,
+          collectionMasterEdition: collectionMasterEditionPDA,
           candidate: publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           metadataProgram: METADATA_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
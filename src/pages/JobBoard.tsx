@@ -10,6 +10,7 @@ import { PublicKey } from '@solana/web3.js';
 import { BN } from '@coral-xyz/anchor';
 import * as anchor from '@coral-xyz/anchor';
 import { extractSkillCategory } from '../utils/badgeHelpers';
+import { getReputationPDA } from '../utils/pdaHelpers';
 
 interface Job {
   id: string;
@@ -37,7 +38,7 @@ interface JobMatch {
 
 export const JobBoard = () => {
   const { publicKey, connected } = useWallet();
-  const { badgeNftProgram, jobBoardProgram, connection } = usePrograms();
+  const { badgeNftProgram, jobBoardProgram, credchainProgram, connection } = usePrograms();
   const toast = useToastContext();
   const [searchTerm, setSearchTerm] = useState('');
   const [selectedType, setSelectedType] = useState<string>('All');
@@ -213,6 +214,19 @@ export const JobBoard = () => {
         nft.freelancer === publicKey.toBase58()
       ).length;
 
+      let userReputation = null;
+      if (credchainProgram) {
+        const [reputationPDA] = getReputationPDA(publicKey);
+        // @ts-expect-error - Account type from IDL
+        const reputation = await credchainProgram.account.reputation.fetchNullable(reputationPDA);
+        if (reputation) {
+          userReputation = {
+            reviewCount: reputation.reviewCount,
+            averageScore: reputation.averageScore,
+          };
+        }
+      }
+
       // Call the AI matching serverless function
       const response = await fetch('/api/match-jobs', {
         method: 'POST',
@@ -228,7 +242,8 @@ export const JobBoard = () => {
             type: job.type,
             duration: job.duration
           })),
-          userCompletions
+          userCompletions,
+          userReputation
         })
       });
 
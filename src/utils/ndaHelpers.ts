@@ -0,0 +1,4 @@
+export async function hashNdaDocument(document: string): Promise<number[]> {
+  const digest = await crypto.subtle.digest('SHA-256', new TextEncoder().encode(document));
+  return Array.from(new Uint8Array(digest));
+}
@@ -41,6 +41,17 @@ export const getTimeSessionPDA = (
 };
 
 
+export const getReputationPDA = (userPubkey: PublicKey): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(PDA_SEEDS.REPUTATION),
+      userPubkey.toBuffer(),
+    ],
+    PROGRAM_IDS.CREDCHAIN
+  );
+};
+
+
 
 export const getBadgeAuthorityPDA = (): [PublicKey, number] => {
   return PublicKey.findProgramAddressSync(
@@ -110,6 +121,34 @@ export const getBadgeMintPDA = (
 };
 
 
+export const getTierConfigPDA = (skillCategory: SkillCategory): [PublicKey, number] => {
+  const skillCategoryString = SKILL_CATEGORY_NAMES[skillCategory];
+  const skillCategoryBytes = Buffer.from(skillCategoryString);
+
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(PDA_SEEDS.TIER_CONFIG),
+      skillCategoryBytes,
+    ],
+    PROGRAM_IDS.BADGE_NFT
+  );
+};
+
+
+export const getCollectionMintPDA = (skillCategory: SkillCategory): [PublicKey, number] => {
+  const skillCategoryString = SKILL_CATEGORY_NAMES[skillCategory];
+  const skillCategoryBytes = Buffer.from(skillCategoryString);
+
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(PDA_SEEDS.COLLECTION_MINT),
+      skillCategoryBytes,
+    ],
+    PROGRAM_IDS.BADGE_NFT
+  );
+};
+
+
 export const getLeaderboardPDA = (skillCategory: SkillCategory): [PublicKey, number] => {
   
   const skillCategoryString = SKILL_CATEGORY_NAMES[skillCategory];
@@ -139,19 +178,34 @@ export const getMetadataPDA = (mintPubkey: PublicKey): [PublicKey, number] => {
 };
 
 
+export const getMasterEditionPDA = (mintPubkey: PublicKey): [PublicKey, number] => {
+  const METADATA_PROGRAM_ID = new PublicKey('metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s');
+
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('metadata'),
+      METADATA_PROGRAM_ID.toBuffer(),
+      mintPubkey.toBuffer(),
+      Buffer.from('edition'),
+    ],
+    METADATA_PROGRAM_ID
+  );
+};
+
+
 export const getAssociatedTokenAddressPDA = (
   ownerPubkey: PublicKey,
-  mintPubkey: PublicKey
+  mintPubkey: PublicKey,
+  tokenProgramId: PublicKey = new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA')
 ): [PublicKey, number] => {
   const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey(
     'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
   );
-  const TOKEN_PROGRAM_ID = new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA');
 
   return PublicKey.findProgramAddressSync(
     [
       ownerPubkey.toBuffer(),
-      TOKEN_PROGRAM_ID.toBuffer(),
+      tokenProgramId.toBuffer(),
       mintPubkey.toBuffer(),
     ],
     ASSOCIATED_TOKEN_PROGRAM_ID
@@ -0,0 +1,414 @@
+import * as anchor from "@coral-xyz/anchor";
+import { AnchorError, BN, Program, type Idl } from "@coral-xyz/anchor";
+import {
+  createMint,
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import { readFileSync } from "fs";
+
+const credchainIdl = JSON.parse(
+  readFileSync(new URL("../idl/credchain.json", import.meta.url), "utf8")
+) as Idl;
+
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111");
+const MILESTONE_AMOUNT = 1_000_000;
+const NO_NDA = new Array(32).fill(0);
+
+type TestContract = {
+  contractId: string;
+  contract: PublicKey;
+  escrow: PublicKey;
+  client: Keypair;
+  freelancer: Keypair;
+  clientTokenAccount: PublicKey;
+  freelancerTokenAccount: PublicKey;
+};
+
+async function expectError(promise: Promise<unknown>, code: string) {
+  let error: unknown;
+  try {
+    await promise;
+  } catch (err) {
+    error = err;
+  }
+  expect(error, `expected ${code}`).to.be.instanceOf(AnchorError);
+  expect((error as AnchorError).error.errorCode.code).to.equal(code);
+}
+
+describe("credchain", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = new Program(credchainIdl, provider);
+  const payer = (provider.wallet as anchor.Wallet).payer;
+  const [configPDA] = PublicKey.findProgramAddressSync([Buffer.from("arbitration-config")], program.programId);
+
+  let mint: PublicKey;
+  let contractCount = 0;
+
+  async function fundedWallet(): Promise<Keypair> {
+    const wallet = Keypair.generate();
+    const signature = await provider.connection.requestAirdrop(wallet.publicKey, 2 * LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(signature, "confirmed");
+    return wallet;
+  }
+
+  async function tokenAccount(owner: PublicKey, amount = 0): Promise<PublicKey> {
+    const account = await getOrCreateAssociatedTokenAccount(provider.connection, payer, mint, owner, true);
+    if (amount > 0) {
+      await mintTo(provider.connection, payer, mint, account.address, payer, amount);
+    }
+    return account.address;
+  }
+
+  async function fetchAccount(name: "contract" | "dispute", address: PublicKey) {
+    const namespace = program.account as unknown as Record<
+      string,
+      { fetch: (address: PublicKey) => Promise<Record<string, unknown>> }
+    >;
+    return namespace[name].fetch(address);
+  }
+
+  async function balance(account: PublicKey): Promise<number> {
+    return Number((await getAccount(provider.connection, account)).amount);
+  }
+
+  async function createContract(milestoneCount = 1): Promise<TestContract> {
+    const contractId = `test-${Date.now()}-${contractCount++}`;
+    const client = await fundedWallet();
+    const freelancer = await fundedWallet();
+    const [contract] = PublicKey.findProgramAddressSync(
+      [Buffer.from("contract"), Buffer.from(contractId)],
+      program.programId
+    );
+    const deadline = new BN(Math.floor(Date.now() / 1000) + 24 * 60 * 60);
+    const milestones = Array.from({ length: milestoneCount }, (_, i) => ({
+      title: `Milestone ${i + 1}`,
+      description: "Test milestone",
+      amount: new BN(MILESTONE_AMOUNT),
+      deadline,
+    }));
+
+    await program.methods
+      .createContract(
+        contractId,
+        "Test contract",
+        "Program test contract",
+        new BN(MILESTONE_AMOUNT * milestoneCount),
+        milestones,
+        mint,
+        false,
+        NO_NDA
+      )
+      .accountsPartial({
+        contract,
+        client: client.publicKey,
+        freelancer: freelancer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([client])
+      .rpc();
+
+    return {
+      contractId,
+      contract,
+      escrow: await tokenAccount(contract),
+      client,
+      freelancer,
+      clientTokenAccount: await tokenAccount(client.publicKey, MILESTONE_AMOUNT * milestoneCount * 2),
+      freelancerTokenAccount: await tokenAccount(freelancer.publicKey),
+    };
+  }
+
+  async function deposit(c: TestContract, amount = MILESTONE_AMOUNT) {
+    await program.methods
+      .depositEscrow(new BN(amount))
+      .accountsPartial({
+        contract: c.contract,
+        client: c.client.publicKey,
+        clientTokenAccount: c.clientTokenAccount,
+        escrowTokenAccount: c.escrow,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([c.client])
+      .rpc();
+  }
+
+  async function submit(c: TestContract, submitter: Keypair = c.freelancer, milestoneIndex = 0) {
+    await program.methods
+      .submitDeliverable(milestoneIndex, "QmTestDeliverable", "deliverable.pdf", "Finished work")
+      .accountsPartial({ contract: c.contract, freelancer: submitter.publicKey })
+      .signers([submitter])
+      .rpc();
+  }
+
+  function approve(c: TestContract, approver: Keypair, contributorAccounts: PublicKey[] = []) {
+    return program.methods
+      .approveMilestone(0)
+      .accountsPartial({
+        contract: c.contract,
+        client: approver.publicKey,
+        escrowTokenAccount: c.escrow,
+        freelancerTokenAccount: contributorAccounts.length > 0 ? null : c.freelancerTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        contributorAccounts.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }))
+      )
+      .signers([approver])
+      .rpc();
+  }
+
+  async function openDispute(c: TestContract): Promise<PublicKey> {
+    const [dispute] = PublicKey.findProgramAddressSync(
+      [Buffer.from("dispute"), c.contract.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .openDispute({ quality: {} }, "Quality", "Deliverable does not match the scope")
+      .accountsPartial({
+        contract: c.contract,
+        config: configPDA,
+        dispute,
+        initiator: c.client.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([c.client])
+      .rpc();
+    return dispute;
+  }
+
+  function acceptSettlement(c: TestContract, dispute: PublicKey, acceptor: Keypair, clientShareBps: number, escrow = c.escrow) {
+    return program.methods
+      .acceptSettlement(clientShareBps)
+      .accountsPartial({
+        dispute,
+        contract: c.contract,
+        config: configPDA,
+        escrowTokenAccount: escrow,
+        clientTokenAccount: c.clientTokenAccount,
+        freelancerTokenAccount: c.freelancerTokenAccount,
+        acceptor: acceptor.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([acceptor])
+      .rpc();
+  }
+
+  async function proposeSettlement(dispute: PublicKey, proposer: Keypair, clientShareBps: number) {
+    await program.methods
+      .proposeSettlement(clientShareBps)
+      .accountsPartial({ dispute, proposer: proposer.publicKey })
+      .signers([proposer])
+      .rpc();
+  }
+
+  before(async () => {
+    mint = await createMint(provider.connection, payer, payer.publicKey, null, 6);
+
+    if (!(await provider.connection.getAccountInfo(configPDA))) {
+      const [programData] = PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        BPF_LOADER_UPGRADEABLE_ID
+      );
+      await program.methods
+        .initializeArbitrationConfig(
+          new BN(24 * 60 * 60),
+          new BN(24 * 60 * 60),
+          2,
+          new BN(0),
+          [Keypair.generate(), Keypair.generate(), Keypair.generate()].map((k) => k.publicKey)
+        )
+        .accountsPartial({
+          config: configPDA,
+          admin: provider.wallet.publicKey,
+          program: program.programId,
+          programData,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+  });
+
+  describe("milestone payouts", () => {
+    it("pays the freelancer when the client approves a milestone", async () => {
+      const c = await createContract();
+      await deposit(c);
+      await submit(c);
+      await approve(c, c.client);
+
+      expect(await balance(c.freelancerTokenAccount)).to.equal(MILESTONE_AMOUNT);
+      expect(await balance(c.escrow)).to.equal(0);
+      const contract = await fetchAccount("contract", c.contract);
+      expect(contract.status).to.deep.equal({ completed: {} });
+    });
+
+    it("rejects approval from a wallet that is neither the client nor a delegate", async () => {
+      const c = await createContract();
+      await deposit(c);
+      await submit(c);
+
+      await expectError(approve(c, await fundedWallet()), "UnauthorizedClient");
+    });
+
+    it("stops a delegate approver at its spending limit", async () => {
+      const c = await createContract();
+      const delegate = await fundedWallet();
+      await program.methods
+        .grantDelegate(delegate.publicKey, { approver: {} }, new BN(MILESTONE_AMOUNT - 1))
+        .accountsPartial({ contract: c.contract, client: c.client.publicKey })
+        .signers([c.client])
+        .rpc();
+      await deposit(c);
+      await submit(c);
+
+      await expectError(approve(c, delegate), "DelegateLimitExceeded");
+    });
+
+    it("splits milestone payments and bonuses across the team", async () => {
+      const c = await createContract();
+      const teammate = await fundedWallet();
+      const teammateTokenAccount = await tokenAccount(teammate.publicKey);
+      await program.methods
+        .configureTeam(
+          [
+            { wallet: c.freelancer.publicKey, shareBps: 6000 },
+            { wallet: teammate.publicKey, shareBps: 4000 },
+          ],
+          []
+        )
+        .accountsPartial({ contract: c.contract, client: c.client.publicKey })
+        .signers([c.client])
+        .rpc();
+      await deposit(c);
+      await submit(c);
+      await approve(c, c.client, [c.freelancerTokenAccount, teammateTokenAccount]);
+
+      expect(await balance(c.freelancerTokenAccount)).to.equal(600_000);
+      expect(await balance(teammateTokenAccount)).to.equal(400_000);
+
+      await program.methods
+        .payMilestoneBonus(0, new BN(100_000))
+        .accountsPartial({
+          contract: c.contract,
+          client: c.client.publicKey,
+          clientTokenAccount: c.clientTokenAccount,
+          freelancerTokenAccount: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          [c.freelancerTokenAccount, teammateTokenAccount].map((pubkey) => ({
+            pubkey,
+            isWritable: true,
+            isSigner: false,
+          }))
+        )
+        .signers([c.client])
+        .rpc();
+
+      expect(await balance(c.freelancerTokenAccount)).to.equal(660_000);
+      expect(await balance(teammateTokenAccount)).to.equal(440_000);
+    });
+  });
+
+  describe("time sessions", () => {
+    function startSession(c: TestContract, freelancer: Keypair, nonce: number) {
+      const nonceBytes = new BN(nonce).toArrayLike(Buffer, "le", 8);
+      const [session] = PublicKey.findProgramAddressSync(
+        [Buffer.from("session"), c.contract.toBuffer(), nonceBytes],
+        program.programId
+      );
+      return program.methods
+        .startTimeSession(c.contractId, 0, new BN(nonce))
+        .accountsPartial({
+          contract: c.contract,
+          session,
+          freelancer: freelancer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([freelancer])
+        .rpc();
+    }
+
+    it("rejects sessions before the contract is funded", async () => {
+      const c = await createContract();
+
+      await expectError(startSession(c, c.freelancer, 0), "InvalidContractStatus");
+    });
+
+    it("only lets the assigned freelancer start a session", async () => {
+      const c = await createContract();
+      await deposit(c);
+
+      await expectError(startSession(c, await fundedWallet(), 0), "UnauthorizedFreelancer");
+      await startSession(c, c.freelancer, 1);
+    });
+  });
+
+  describe("dispute settlements", () => {
+    it("splits escrow by the accepted settlement", async () => {
+      const c = await createContract();
+      await deposit(c);
+      const dispute = await openDispute(c);
+      const clientBalance = await balance(c.clientTokenAccount);
+
+      await proposeSettlement(dispute, c.client, 3000);
+      await acceptSettlement(c, dispute, c.freelancer, 3000);
+
+      expect(await balance(c.clientTokenAccount)).to.equal(clientBalance + 300_000);
+      expect(await balance(c.freelancerTokenAccount)).to.equal(700_000);
+      const contract = await fetchAccount("contract", c.contract);
+      expect(contract.status).to.deep.equal({ cancelled: {} });
+      const settled = await fetchAccount("dispute", dispute);
+      expect(settled.status).to.deep.equal({ settled: {} });
+    });
+
+    it("completes the contract when the freelancer receives everything", async () => {
+      const c = await createContract();
+      await deposit(c);
+      const dispute = await openDispute(c);
+
+      await proposeSettlement(dispute, c.client, 0);
+      await acceptSettlement(c, dispute, c.freelancer, 0);
+
+      expect(await balance(c.freelancerTokenAccount)).to.equal(MILESTONE_AMOUNT);
+      const contract = await fetchAccount("contract", c.contract);
+      expect(contract.status).to.deep.equal({ completed: {} });
+    });
+
+    it("does not let the proposer accept their own offer", async () => {
+      const c = await createContract();
+      await deposit(c);
+      const dispute = await openDispute(c);
+      await proposeSettlement(dispute, c.client, 5000);
+
+      await expectError(acceptSettlement(c, dispute, c.client, 5000), "UnauthorizedDispute");
+    });
+
+    it("rejects an acceptance made against a changed offer", async () => {
+      const c = await createContract();
+      await deposit(c);
+      const dispute = await openDispute(c);
+      await proposeSettlement(dispute, c.client, 5000);
+
+      await expectError(acceptSettlement(c, dispute, c.freelancer, 2000), "SettlementOfferChanged");
+    });
+
+    it("rejects an escrow account the contract does not own", async () => {
+      const c = await createContract();
+      await deposit(c);
+      const dispute = await openDispute(c);
+      await proposeSettlement(dispute, c.client, 5000);
+
+      await expectError(
+        acceptSettlement(c, dispute, c.freelancer, 5000, c.clientTokenAccount),
+        "InvalidEscrowAccount"
+      );
+    });
+  });
+});